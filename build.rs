@@ -0,0 +1,91 @@
+//! Fingerprints every solver module, so that cached results can tell when a solver changed.
+
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=utils/src");
+
+    // shared code can change any answer, so it is part of every fingerprint: the utils crate and
+    // everything in the runner that solvers use, like the solver traits, logging and profiling
+    let solvers = Path::new("src/solver");
+    let runner = files(Path::new("src"))
+        .into_iter()
+        .filter(|path| !path.starts_with(solvers) || path.parent() == Some(solvers));
+    let shared = files(Path::new("utils/src"))
+        .into_iter()
+        .chain(runner)
+        .fold(FNV_OFFSET, |hash, path| fnv1a(hash, &read(&path)));
+
+    let mut lines = Vec::new();
+    for year_dir in entries(Path::new("src/solver")) {
+        let Some(year) = number(&year_dir, "year_") else {
+            continue;
+        };
+        // the year's module registers the solvers and their variants
+        let registry = read(&year_dir.join("mod.rs"));
+
+        for day_path in entries(&year_dir) {
+            let Some(day) = number(&day_path, "day_") else {
+                continue;
+            };
+            let hash = files(&day_path)
+                .iter()
+                .fold(fnv1a(shared, &registry), |hash, path| {
+                    fnv1a(hash, &read(path))
+                });
+            lines.push(format!("    (({day}, {year}), {hash:#018x}),"));
+        }
+    }
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("fingerprints.rs");
+    let source = format!(
+        "/// Hash of each solver's source, by (day, year).\n\
+         static FINGERPRINTS: &[((i32, i32), u64)] = &[\n{}\n];\n",
+        lines.join("\n")
+    );
+    fs::write(out, source).unwrap();
+}
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// The files and directories directly in `dir`, sorted.
+fn entries(dir: &Path) -> Vec<PathBuf> {
+    let mut paths = fs::read_dir(dir)
+        .map(|dir| dir.filter_map(|e| Some(e.ok()?.path())).collect::<Vec<_>>())
+        .unwrap_or_default();
+    paths.sort();
+    paths
+}
+
+/// `path` itself if it is a source file, or every source file below it, in a stable order.
+fn files(path: &Path) -> Vec<PathBuf> {
+    if path.is_dir() {
+        return entries(path).iter().flat_map(|p| files(p)).collect();
+    }
+    if path.extension().is_some_and(|e| e == "rs") {
+        vec![path.to_path_buf()]
+    } else {
+        vec![]
+    }
+}
+
+/// The number in a module name like `day_17.rs` or `year_2024`.
+fn number(path: &Path, prefix: &str) -> Option<i32> {
+    path.file_stem()?
+        .to_str()?
+        .strip_prefix(prefix)?
+        .parse()
+        .ok()
+}
+
+fn read(path: &Path) -> Vec<u8> {
+    fs::read(path).unwrap_or_default()
+}
@@ -0,0 +1,38 @@
+//! 2024 day 1 as a plugin, to start new plugins from.
+//!
+//! Build it with `cargo build -p aoc-plugin-example` and copy the library from `target/debug`
+//! into the plugins directory, where it takes the place of the built-in solver.
+
+use std::collections::HashMap;
+use std::num::ParseIntError;
+
+fn parse(input: &str) -> Result<(Vec<u32>, Vec<u32>), ParseIntError> {
+    let mut lists = (Vec::new(), Vec::new());
+    for line in input.lines() {
+        let mut numbers = line.split_whitespace();
+        lists.0.push(numbers.next().unwrap_or_default().parse()?);
+        lists.1.push(numbers.next().unwrap_or_default().parse()?);
+    }
+    Ok(lists)
+}
+
+fn part_1(input: &str) -> Result<u32, ParseIntError> {
+    let (mut left, mut right) = parse(input)?;
+    left.sort_unstable();
+    right.sort_unstable();
+    Ok(left.iter().zip(&right).map(|(a, b)| a.abs_diff(*b)).sum())
+}
+
+fn part_2(input: &str) -> Result<u32, ParseIntError> {
+    let (left, right) = parse(input)?;
+    let mut counts = HashMap::new();
+    for n in right {
+        *counts.entry(n).or_insert(0) += 1;
+    }
+    Ok(left.iter().map(|n| n * counts.get(n).unwrap_or(&0)).sum())
+}
+
+aoc_plugin::plugin! {
+    (1, 2024, 1) => part_1,
+    (1, 2024, 2) => part_2,
+}
@@ -0,0 +1,132 @@
+//! The C ABI between the runner and solver plugins, so that a day can be built as a small
+//! `cdylib` and loaded from the plugins directory instead of being compiled into the runner.
+//!
+//! A plugin exports two functions: [`VERSION_SYMBOL`], returning [`ABI_VERSION`], and
+//! [`REGISTER_SYMBOL`], which calls back into the runner once per solved part. The [`plugin!`]
+//! macro writes both from plain Rust functions:
+//!
+//! ```ignore
+//! aoc_plugin::plugin! {
+//!     (1, 2024, 1) => part_1,
+//!     (1, 2024, 2) => part_2,
+//! }
+//! ```
+//!
+//! Only C types cross the boundary, and answers are written into a buffer owned by the runner,
+//! so plugins don't need to be built with the same compiler or allocator.
+
+use std::ffi::c_void;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::{slice, str};
+
+/// Bumped whenever any of the types below change.
+pub const ABI_VERSION: u32 = 1;
+
+/// A [`VersionFn`].
+pub const VERSION_SYMBOL: &[u8] = b"aoc_plugin_abi_version\0";
+/// A [`RegisterFn`].
+pub const REGISTER_SYMBOL: &[u8] = b"aoc_plugin_register\0";
+
+pub type VersionFn = unsafe extern "C" fn() -> u32;
+
+/// Registers every part the plugin solves by calling `add_part` with `registrar`.
+pub type RegisterFn = unsafe extern "C" fn(registrar: *mut c_void, add_part: AddPartFn);
+
+/// Registers `solve` as `part` (1 or 2) of the day; `data` is passed back to it on every call.
+pub type AddPartFn = unsafe extern "C" fn(
+    registrar: *mut c_void,
+    day: i32,
+    year: i32,
+    part: u8,
+    solve: SolveFn,
+    data: *const c_void,
+);
+
+/// Solves a part for the `len` bytes of UTF-8 at `input`, passing the answer to `write`.
+///
+/// Returns 0 on success; anything else means that an error message was written instead.
+pub type SolveFn = unsafe extern "C" fn(
+    data: *const c_void,
+    input: *const u8,
+    len: usize,
+    out: *mut c_void,
+    write: WriteFn,
+) -> i32;
+
+/// Appends the `len` bytes at `ptr` to `out`.
+pub type WriteFn = unsafe extern "C" fn(out: *mut c_void, ptr: *const u8, len: usize);
+
+/// A part as registered by [`plugin!`], with its answer or error already formatted.
+pub type Part = dyn Fn(&str) -> Result<String, String> + Sync;
+
+/// Adapts a solver function to [`Part`].
+pub fn part<T: Display, E: Display>(
+    f: impl Fn(&str) -> Result<T, E> + Sync + 'static,
+) -> Box<Part> {
+    Box::new(move |input| f(input).map(|a| a.to_string()).map_err(|e| e.to_string()))
+}
+
+/// The [`SolveFn`] for parts registered by [`plugin!`], where `data` points to a `&'static Part`.
+///
+/// # Safety
+///
+/// `data` must come from [`plugin!`], and the other arguments must follow [`SolveFn`].
+pub unsafe extern "C" fn solve(
+    data: *const c_void,
+    input: *const u8,
+    len: usize,
+    out: *mut c_void,
+    write: WriteFn,
+) -> i32 {
+    let part = *(data as *const &Part);
+    let input = slice::from_raw_parts(input, len);
+    // a panic can't unwind into the runner, so it is reported like any other error
+    let result = panic::catch_unwind(AssertUnwindSafe(|| match str::from_utf8(input) {
+        Ok(input) => part(input),
+        Err(e) => Err(format!("input is not UTF-8: {e}")),
+    }))
+    .unwrap_or_else(|_| Err("panicked".to_string()));
+
+    let (status, text) = match &result {
+        Ok(answer) => (0, answer),
+        Err(error) => (1, error),
+    };
+    write(out, text.as_ptr(), text.len());
+    status
+}
+
+/// Exports the plugin entry points for parts given as `(day, year, part) => function`, where
+/// each function takes the whole input and returns a `Result` of anything `Display`.
+#[macro_export]
+macro_rules! plugin {
+    ($(($day:expr, $year:expr, $part:expr) => $solve:expr),* $(,)?) => {
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_abi_version() -> u32 {
+            $crate::ABI_VERSION
+        }
+
+        /// # Safety
+        ///
+        /// Only to be called by the runner, with its own `add_part`.
+        #[no_mangle]
+        pub unsafe extern "C" fn aoc_plugin_register(
+            registrar: *mut ::std::ffi::c_void,
+            add_part: $crate::AddPartFn,
+        ) {
+            $({
+                static PART: ::std::sync::OnceLock<&'static $crate::Part> =
+                    ::std::sync::OnceLock::new();
+                let part = PART.get_or_init(|| ::std::boxed::Box::leak($crate::part($solve)));
+                add_part(
+                    registrar,
+                    $day,
+                    $year,
+                    $part,
+                    $crate::solve,
+                    part as *const &'static $crate::Part as *const ::std::ffi::c_void,
+                );
+            })*
+        }
+    };
+}
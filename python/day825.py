@@ -1,33 +1,12 @@
 import heapq as hq
 import math
+import os
+import sys
 from typing import TypeAlias
 
-input = """162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689
-"""
-limit = 10
-
-with open("data/2025/full-8-25.txt") as f:
-    input = f.read()
-limit = 1000
+input = sys.stdin.read()
+# the example only connects the 10 closest pairs
+limit = 10 if os.environ.get("AOC_INPUT") == "example" else 1000
 
 Point: TypeAlias = tuple[float, float, float]
 
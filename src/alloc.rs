@@ -0,0 +1,123 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator to track live heap bytes, the peak, and the number of allocations.
+///
+/// Only installed as the global allocator with the `alloc-stats` feature.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    #[inline]
+    fn grow(size: usize) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    #[inline]
+    fn shrink(size: usize) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::shrink(layout.size());
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            if new_size > layout.size() {
+                Self::grow(new_size - layout.size());
+            } else {
+                Self::shrink(layout.size() - new_size);
+            }
+        }
+        new
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct AllocStats {
+    /// Most heap bytes in use at once, above what was in use before the measurement
+    pub peak: usize,
+    /// Number of allocations and reallocations
+    pub count: usize,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+        let mut peak = self.peak as f64;
+        let mut unit = 0;
+        while peak >= 1024.0 && unit < UNITS.len() - 1 {
+            peak /= 1024.0;
+            unit += 1;
+        }
+
+        if unit == 0 {
+            write!(f, "peak {} B, {} allocs", self.peak, self.count)
+        } else {
+            write!(f, "peak {:.2} {}, {} allocs", peak, UNITS[unit], self.count)
+        }
+    }
+}
+
+/// Runs `f`, returning the allocation stats for the call if the counting allocator is installed.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !cfg!(feature = "alloc-stats") {
+        return (f(), None);
+    }
+
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    let count = COUNT.load(Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = AllocStats {
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(base),
+        count: COUNT.load(Ordering::Relaxed) - count,
+    };
+    (result, Some(stats))
+}
+
+#[test]
+fn should_format_stats() {
+    let stats = AllocStats {
+        peak: 512,
+        count: 3,
+    };
+    assert_eq!("peak 512 B, 3 allocs", stats.to_string());
+
+    let stats = AllocStats {
+        peak: 3 * 1024 * 1024 / 2,
+        count: 10,
+    };
+    assert_eq!("peak 1.50 MiB, 10 allocs", stats.to_string());
+}
@@ -0,0 +1,109 @@
+//! Results of earlier runs on the full inputs, reused while neither the solver nor the input
+//! changed.
+//!
+//! Solvers are identified by a fingerprint of their source made by `build.rs`, so any edit to a
+//! day's module, its year's registrations or the `utils` crate invalidates its results. Days
+//! from plugins use a hash of the library instead.
+
+use crate::day::{Day, SolverDatabase};
+use crate::runner::DayResult;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+include!(concat!(env!("OUT_DIR"), "/fingerprints.rs"));
+
+/// The cache file inside the data directory.
+pub const CACHE_FILE: &str = ".cache.json";
+
+/// Hash of the source of `day`'s solver, or of the plugin solving it, if it has one.
+pub fn fingerprint(day: Day) -> Option<u64> {
+    if let Some(plugin) = SolverDatabase::global().get_plugin(&day) {
+        return Some(plugin.fingerprint);
+    }
+    FINGERPRINTS
+        .iter()
+        .find(|(key, _)| *key == (day.day, day.year))
+        .map(|(_, hash)| *hash)
+}
+
+/// FNV-1a, which unlike the std hashers is stable between builds.
+pub fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct ResultCache(BTreeMap<String, Entry>);
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    fingerprint: u64,
+    input: u64,
+    result: DayResult,
+}
+
+impl ResultCache {
+    /// Reads the cache from `path`, starting over if it is missing or unreadable.
+    pub fn load(path: &Path) -> Self {
+        fs::read(path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        fs::write(path, serde_json::to_vec(self)?)?;
+        Ok(())
+    }
+
+    /// The result stored for `day`, if it was made by the current solver from the same input.
+    pub fn get(&self, day: Day, input: u64) -> Option<&DayResult> {
+        let entry = self.0.get(&day.to_string())?;
+        (Some(entry.fingerprint) == fingerprint(day) && entry.input == input)
+            .then_some(&entry.result)
+    }
+
+    /// Stores `result` for `day`, unless there's nothing to tell when it gets stale or a part is
+    /// missing.
+    pub fn insert(&mut self, day: Day, input: u64, result: &DayResult) {
+        let Some(fingerprint) = fingerprint(day) else {
+            return;
+        };
+        let answer = result.answer();
+        let parts = [answer.part_1(), answer.part_2()];
+        if parts.iter().any(|p| p.is_none_or(|p| !p.is_implemented())) {
+            return;
+        }
+
+        self.0.insert(
+            day.to_string(),
+            Entry {
+                fingerprint,
+                input,
+                result: result.clone(),
+            },
+        );
+    }
+}
+
+#[test]
+fn should_only_hit_for_same_input() {
+    // given
+    let day = Day::new(1, 2024);
+    let result = DayResult::Combined {
+        answer: ("11".into(), "31".into()),
+        time: std::time::Duration::from_millis(3),
+        alloc: None,
+    };
+    let mut cache = ResultCache::default();
+    // when
+    cache.insert(day, hash(b"3   4"), &result);
+    // then
+    assert!(fingerprint(day).is_some());
+    assert!(cache.get(day, hash(b"3   4")).is_some());
+    assert!(cache.get(day, hash(b"3   5")).is_none());
+    assert!(cache.get(Day::new(2, 2024), hash(b"3   4")).is_none());
+}
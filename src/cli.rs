@@ -1,9 +1,13 @@
-use crate::day::{Answer, Day, DaysMeta, Example};
-use clap::{arg, command, value_parser, Command};
+use crate::config::OutputFormat;
+use crate::crypt;
+use crate::day::{Answer, AnswerValue, Day, DaysMeta, Example};
+use crate::isolate::{self, Isolation};
+use clap::error::ErrorKind;
+use clap::{arg, command, value_parser, Arg, ArgAction, ArgMatches, Command};
 use std::ffi::OsString;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Cursor};
+use std::io::{BufRead, Cursor};
 use std::path::PathBuf;
+use std::time::Duration;
 use std::{env, io};
 use thiserror::Error;
 
@@ -12,13 +16,103 @@ pub enum RunType {
     Interactive,
     Args(RunArgs),
     All,
+    Submit(SubmitArgs),
+    Profile(ProfileArgs),
+    Gen(GenArgs),
+    /// Runs every variant of a day on the same input and compares them
+    Diff(RunArgs),
+    /// Answers JSON requests on stdin until it is closed
+    Serve,
+    /// Shows what exists for each day, optionally only for one year
+    List(Option<i32>),
+    /// Shows the stars earned each day, optionally only for one year
+    Calendar(Option<i32>),
+    /// Encrypts the full inputs in the meta
+    Encrypt(CryptArgs),
+    /// Decrypts the encrypted full inputs in the meta
+    Decrypt(CryptArgs),
+    /// Plays or exports the visualization of a day
+    Visualize(VisualizeArgs),
+    /// Runs a day on its full input for `--isolate` and prints the result as JSON
+    Isolated(Day),
+    /// Takes the example of a day from a saved puzzle page
+    ImportExamples(ImportArgs),
 }
 
-impl RunType {
-    pub fn parse() -> Self {
-        Self::parse_from(env::args_os())
+/// Everything given on the command line: what to run and how much to log while running it.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Cli {
+    pub run: RunType,
+    pub log: LogArgs,
+    /// Overrides the data directory from the config
+    pub data_dir: Option<PathBuf>,
+    /// Overrides the output format from the config
+    pub format: Option<OutputFormat>,
+    /// Runs every day even if the config enables the result cache
+    pub no_cache: bool,
+    /// Runs each day of `--all` in a child process with these limits
+    pub isolate: Option<Isolation>,
+    /// How many times each day of `--all` runs, for median times
+    pub runs: usize,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct LogArgs {
+    pub verbosity: u8,
+    /// Only log for these days, or for every day if empty
+    pub days: Vec<Day>,
+}
+
+impl Cli {
+    /// Parses the process arguments, using `year` for days given without one.
+    pub fn parse(year: Option<i32>) -> Self {
+        Self::parse_with_year(env::args_os(), year)
+    }
+
+    pub fn parse_from<I, T>(itr: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        Self::parse_with_year(itr, None)
     }
 
+    pub fn parse_with_year<I, T>(itr: I, year: Option<i32>) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let matches = RunType::command().get_matches_from(itr);
+        Self {
+            run: RunType::from_matches(&matches, year),
+            log: LogArgs::from_matches(&matches),
+            data_dir: matches.get_one::<PathBuf>("data-dir").cloned(),
+            format: matches.get_one::<OutputFormat>("format").copied(),
+            no_cache: matches.get_flag("no-cache"),
+            isolate: matches.get_flag("isolate").then(|| {
+                Isolation::new(
+                    *matches.get_one::<u64>("memory-limit").unwrap(),
+                    Duration::from_secs(*matches.get_one::<u64>("cpu-limit").unwrap()),
+                )
+            }),
+            runs: *matches.get_one::<u64>("runs").unwrap() as usize,
+        }
+    }
+}
+
+impl LogArgs {
+    fn from_matches(matches: &ArgMatches) -> Self {
+        Self {
+            verbosity: matches.get_count("verbose"),
+            days: matches
+                .get_many::<Day>("log-day")
+                .map(|days| days.copied().collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl RunType {
     pub fn command() -> Command {
         command!()
             .arg(
@@ -73,6 +167,265 @@ impl RunType {
             .arg(arg!(
                 -'2' --two "Run part 2"
             ))
+            .arg(
+                arg!(
+                    --loop <SECONDS> "Run repeatedly for this long, for attaching a profiler"
+                )
+                .required(false)
+                .value_parser(|s: &str| {
+                    s.parse::<f64>()
+                        .ok()
+                        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                        .filter(|d| !d.is_zero())
+                        .ok_or_else(|| format!("expected a positive number of seconds, got {s:?}"))
+                }),
+            )
+            .arg(
+                arg!(
+                    --variant <NAME> "Run a named variant of the solver"
+                )
+                .required(false)
+                .value_parser(value_parser!(String)),
+            )
+            .arg(
+                arg!(
+                    --visualize "Play the day's visualization instead of solving it"
+                )
+                .conflicts_with_all(["variant", "loop", "diff"]),
+            )
+            .arg(
+                arg!(
+                    --fps <FPS> "Frames per second for --visualize"
+                )
+                .default_value("10")
+                .value_parser(value_parser!(u32).range(1..)),
+            )
+            .arg(
+                arg!(
+                    --export <DIR> "Write the frames of --visualize as images to this directory"
+                )
+                .required(false)
+                .requires("visualize")
+                .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(arg!(
+                --"no-cache" "Run every day with -a, even the ones with a cached result"
+            ))
+            .arg(
+                arg!(
+                    --isolate "Run each day of -a in a child process with memory and CPU time limits"
+                )
+                .requires("all"),
+            )
+            .arg(
+                arg!(
+                    --"memory-limit" <MIB> "Memory cap per day for --isolate, in MiB"
+                )
+                .default_value("4096")
+                .value_parser(value_parser!(u64).range(1..)),
+            )
+            .arg(
+                arg!(
+                    --"cpu-limit" <SECONDS> "CPU time cap per day for --isolate"
+                )
+                .default_value("60")
+                .value_parser(value_parser!(u64).range(1..)),
+            )
+            .arg(
+                arg!(
+                    --runs <N> "Run each day of -a this many times and show the median times"
+                )
+                .default_value("1")
+                .requires("all")
+                .value_parser(value_parser!(u64).range(1..)),
+            )
+            .arg(
+                Arg::new("isolated-day")
+                    .long(&isolate::CHILD_FLAG[2..])
+                    .hide(true)
+                    .value_parser(|s: &str| {
+                        s.parse::<Day>()
+                            .map_err(|_| format!("expected DAY-YEAR, got {s:?}"))
+                    }),
+            )
+            .arg(
+                arg!(
+                    --diff "Run every variant of the solver and compare answers and times"
+                )
+                .conflicts_with_all(["variant", "loop"]),
+            )
+            .arg(
+                arg!(
+                    -v --verbose... "Show solver logs (-vv for more)"
+                )
+                .global(true),
+            )
+            .arg(
+                arg!(
+                    --"log-day" <DAY> "Only show logs for this day, as DAY-YEAR (repeatable)"
+                )
+                .required(false)
+                .global(true)
+                .action(ArgAction::Append)
+                .value_parser(|s: &str| {
+                    s.parse::<Day>()
+                        .map_err(|_| format!("expected DAY-YEAR, got {s:?}"))
+                }),
+            )
+            .arg(
+                arg!(
+                    --"data-dir" <DIR> "The directory with meta.json, instead of the one from aoc.toml"
+                )
+                .required(false)
+                .global(true)
+                .value_parser(value_parser!(PathBuf)),
+            )
+            .arg(
+                arg!(
+                    --format <FORMAT> "Print results as text or json"
+                )
+                .required(false)
+                .global(true)
+                .value_parser(|s: &str| s.parse::<OutputFormat>()),
+            )
+            .subcommand(
+                Command::new("submit")
+                    .about("Solve a part on the full input and submit the answer")
+                    .arg(
+                        arg!(
+                            -d --day <DAY> "The day to submit"
+                        )
+                        .value_parser(value_parser!(i32)),
+                    )
+                    .arg(
+                        arg!(
+                            -y --year <YEAR> "The year to submit"
+                        )
+                        .required(false)
+                        .value_parser(value_parser!(i32)),
+                    )
+                    .arg(
+                        arg!(
+                            -p --part <PART> "The part to submit"
+                        )
+                        .value_parser(value_parser!(u8).range(1..=2)),
+                    ),
+            )
+            .subcommand(
+                Command::new("list")
+                    .about("List each day with its solver and meta status")
+                    .arg(
+                        arg!(
+                            -y --year <YEAR> "Only list this year"
+                        )
+                        .required(false)
+                        .value_parser(value_parser!(i32)),
+                    ),
+            )
+            .subcommand(
+                Command::new("calendar")
+                    .about("Show the stars from verified answers as an advent calendar")
+                    .arg(
+                        arg!(
+                            -y --year <YEAR> "Only show this year"
+                        )
+                        .required(false)
+                        .value_parser(value_parser!(i32)),
+                    ),
+            )
+            .subcommand(
+                Command::new("encrypt")
+                    .about("Encrypt the full inputs so that they can be committed")
+                    .args(Self::crypt_args("Delete the plain inputs afterwards")),
+            )
+            .subcommand(
+                Command::new("decrypt")
+                    .about("Decrypt the encrypted full inputs back to plain files")
+                    .args(Self::crypt_args("Delete the encrypted inputs afterwards")),
+            )
+            .subcommand(
+                Command::new("import-examples")
+                    .about("Write the example and its answers from a saved puzzle page to the meta")
+                    .arg(
+                        arg!(
+                            <PAGE> "The puzzle page, saved as HTML"
+                        )
+                        .value_parser(value_parser!(PathBuf)),
+                    )
+                    .arg(
+                        arg!(
+                            -d --day <DAY> "The day of the puzzle"
+                        )
+                        .value_parser(value_parser!(i32)),
+                    )
+                    .arg(
+                        arg!(
+                            -y --year <YEAR> "The year of the puzzle"
+                        )
+                        .required(false)
+                        .value_parser(value_parser!(i32)),
+                    )
+                    .arg(
+                        arg!(
+                            -b --block <N> "Use the Nth code block as the example instead of asking"
+                        )
+                        .required(false)
+                        .value_parser(value_parser!(u64).range(1..)),
+                    ),
+            )
+            .subcommand(
+                Command::new("serve").about(
+                    "Answer newline-delimited JSON requests on stdin, for editor integration",
+                ),
+            )
+            .subcommand(
+                Command::new("gen")
+                    .about("Generate a random input for a day, to run with -f")
+                    .arg(
+                        arg!(
+                            -d --day <DAY> "The day to generate for"
+                        )
+                        .value_parser(value_parser!(i32)),
+                    )
+                    .arg(
+                        arg!(
+                            -y --year <YEAR> "The year to generate for"
+                        )
+                        .required(false)
+                        .value_parser(value_parser!(i32)),
+                    )
+                    .arg(
+                        arg!(
+                            -s --seed <SEED> "Seed for the random generator"
+                        )
+                        .default_value("0")
+                        .value_parser(value_parser!(u64)),
+                    )
+                    .arg(
+                        arg!(
+                            -n --size <SIZE> "How big the input should be, e.g. the number of lines"
+                        )
+                        .default_value("1000")
+                        .value_parser(value_parser!(usize)),
+                    )
+                    .arg(
+                        arg!(
+                            -o --output <FILE> "The file to write the input to"
+                        )
+                        .value_parser(value_parser!(PathBuf)),
+                    ),
+            )
+    }
+
+    fn crypt_args(remove: &'static str) -> [Arg; 2] {
+        [
+            arg!(
+                -y --year <YEAR> "Only this year"
+            )
+            .required(false)
+            .value_parser(value_parser!(i32)),
+            arg!(--remove).help(remove),
+        ]
     }
 
     pub fn parse_from<I, T>(itr: I) -> Self
@@ -80,14 +433,67 @@ impl RunType {
         I: IntoIterator<Item = T>,
         T: Into<OsString> + Clone,
     {
-        let matches = Self::command().get_matches_from(itr);
+        Cli::parse_from(itr).run
+    }
 
-        if matches.get_flag("all") {
+    fn from_matches(matches: &ArgMatches, default_year: Option<i32>) -> Self {
+        let year = |matches: &ArgMatches| matches.get_one::<i32>("year").copied().or(default_year);
+        let required_year = |matches: &ArgMatches| {
+            year(matches).unwrap_or_else(|| {
+                Self::command()
+                    .error(
+                        ErrorKind::MissingRequiredArgument,
+                        "--year is required when aoc.toml has no default year",
+                    )
+                    .exit()
+            })
+        };
+
+        if let Some(("submit", matches)) = matches.subcommand() {
+            let day = Day {
+                day: *matches.get_one::<i32>("day").unwrap(),
+                year: required_year(matches),
+            };
+            let part = match matches.get_one::<u8>("part").unwrap() {
+                1 => PartArgs::P1,
+                _ => PartArgs::P2,
+            };
+            RunType::Submit(SubmitArgs { day, part })
+        } else if let Some(("list", matches)) = matches.subcommand() {
+            RunType::List(matches.get_one::<i32>("year").copied())
+        } else if let Some(("calendar", matches)) = matches.subcommand() {
+            RunType::Calendar(matches.get_one::<i32>("year").copied())
+        } else if let Some(("encrypt", matches)) = matches.subcommand() {
+            RunType::Encrypt(CryptArgs::from_matches(matches))
+        } else if let Some(("decrypt", matches)) = matches.subcommand() {
+            RunType::Decrypt(CryptArgs::from_matches(matches))
+        } else if let Some(("import-examples", matches)) = matches.subcommand() {
+            RunType::ImportExamples(ImportArgs {
+                day: Day {
+                    day: *matches.get_one::<i32>("day").unwrap(),
+                    year: required_year(matches),
+                },
+                page: matches.get_one::<PathBuf>("PAGE").unwrap().clone(),
+                block: matches.get_one::<u64>("block").map(|&n| n as usize),
+            })
+        } else if let Some(("serve", _)) = matches.subcommand() {
+            RunType::Serve
+        } else if let Some(("gen", matches)) = matches.subcommand() {
+            RunType::Gen(GenArgs {
+                day: Day {
+                    day: *matches.get_one::<i32>("day").unwrap(),
+                    year: required_year(matches),
+                },
+                seed: *matches.get_one::<u64>("seed").unwrap(),
+                size: *matches.get_one::<usize>("size").unwrap(),
+                output: matches.get_one::<PathBuf>("output").unwrap().clone(),
+            })
+        } else if let Some(&day) = matches.get_one::<Day>("isolated-day") {
+            RunType::Isolated(day)
+        } else if matches.get_flag("all") {
             RunType::All
-        } else if let (Some(&day), Some(&year)) = (
-            matches.get_one::<i32>("day"),
-            matches.get_one::<i32>("year"),
-        ) {
+        } else if let Some(&day) = matches.get_one::<i32>("day") {
+            let year = required_year(matches);
             let part = match (matches.get_flag("one"), matches.get_flag("two")) {
                 (true, true) | (false, false) => PartArgs::Both,
                 (true, false) => PartArgs::P1,
@@ -105,11 +511,25 @@ impl RunType {
             } else {
                 RunSource::Single(SingleSource::Full)
             };
-            RunType::Args(RunArgs {
+            let args = RunArgs {
                 day: Day { day, year },
                 part,
                 source,
-            })
+                variant: matches.get_one::<String>("variant").cloned(),
+            };
+            match matches.get_one::<Duration>("loop") {
+                Some(&duration) => RunType::Profile(ProfileArgs {
+                    run: args,
+                    duration,
+                }),
+                None if matches.get_flag("diff") => RunType::Diff(args),
+                None if matches.get_flag("visualize") => RunType::Visualize(VisualizeArgs {
+                    run: args,
+                    fps: *matches.get_one::<u32>("fps").unwrap(),
+                    export: matches.get_one::<PathBuf>("export").cloned(),
+                }),
+                None => RunType::Args(args),
+            }
         } else {
             RunType::Interactive
         }
@@ -121,6 +541,44 @@ pub struct RunArgs {
     pub day: Day,
     pub part: PartArgs,
     pub source: RunSource,
+    /// A named solver variant to run instead of the main solver
+    pub variant: Option<String>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ProfileArgs {
+    pub run: RunArgs,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct VisualizeArgs {
+    pub run: RunArgs,
+    pub fps: u32,
+    /// Where to write the frames as images instead of playing them
+    pub export: Option<PathBuf>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct SubmitArgs {
+    pub day: Day,
+    pub part: PartArgs,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ImportArgs {
+    pub day: Day,
+    pub page: PathBuf,
+    /// The example's code block, counting from 1, or `None` to ask
+    pub block: Option<usize>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct GenArgs {
+    pub day: Day,
+    pub seed: u64,
+    pub size: usize,
+    pub output: PathBuf,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -136,6 +594,22 @@ pub enum RunSource {
     Example(ExampleSource),
 }
 
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CryptArgs {
+    pub year: Option<i32>,
+    /// Whether to delete the files that were converted
+    pub remove: bool,
+}
+
+impl CryptArgs {
+    fn from_matches(matches: &ArgMatches) -> Self {
+        Self {
+            year: matches.get_one::<i32>("year").copied(),
+            remove: matches.get_flag("remove"),
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ReadersError {
     #[error(transparent)]
@@ -156,13 +630,21 @@ pub enum ExampleReader {
     },
     Multi {
         file_1: Box<dyn BufRead>,
-        expected_answer_1: String,
+        expected_answer_1: AnswerValue,
         file_2: Box<dyn BufRead>,
-        expected_answer_2: String,
+        expected_answer_2: AnswerValue,
     },
 }
 
 impl RunSource {
+    /// Whether the answers produced from this source are for the full input.
+    pub fn uses_full(&self) -> bool {
+        matches!(
+            self,
+            RunSource::Single(SingleSource::Full) | RunSource::Example(ExampleSource::Main)
+        )
+    }
+
     pub fn to_readers(self, meta: &DaysMeta, day: Day) -> Result<SourceReader, ReadersError> {
         match self {
             RunSource::Example(example) => {
@@ -174,29 +656,21 @@ impl RunSource {
                     Example::Single {
                         path,
                         expected_answer,
-                    } => {
-                        let file = File::open(path)?;
-                        Ok(ExampleReader::Single {
-                            file: Box::new(BufReader::new(file)),
-                            expected_answer,
-                        })
-                    }
+                    } => Ok(ExampleReader::Single {
+                        file: crypt::open(&path)?,
+                        expected_answer,
+                    }),
                     Example::Multi {
                         path_1,
                         expected_answer_1,
                         path_2,
                         expected_answer_2,
-                    } => {
-                        let file_1 = File::open(path_1)?;
-                        let file_2 = File::open(path_2)?;
-
-                        Ok(ExampleReader::Multi {
-                            file_1: Box::new(Box::new(BufReader::new(file_1))),
-                            expected_answer_1,
-                            file_2: Box::new(Box::new(BufReader::new(file_2))),
-                            expected_answer_2,
-                        })
-                    }
+                    } => Ok(ExampleReader::Multi {
+                        file_1: crypt::open(&path_1)?,
+                        expected_answer_1,
+                        file_2: crypt::open(&path_2)?,
+                        expected_answer_2,
+                    }),
                 };
 
                 let reader = reader?;
@@ -204,17 +678,13 @@ impl RunSource {
                 match example {
                     ExampleSource::ExampleOnly => Ok(SourceReader::Example(reader, None)),
                     ExampleSource::Main => {
-                        let file = File::open(&day_info.full)?;
-                        let full_reader = Box::new(BufReader::new(file));
+                        let full_reader = crypt::open(&day_info.full)?;
                         Ok(SourceReader::Example(reader, Some(full_reader)))
                     }
                 }
             }
             RunSource::Single(single) => match single {
-                SingleSource::File(file) => {
-                    let file = File::open(file)?;
-                    Ok(SourceReader::Simple(Box::new(BufReader::new(file))))
-                }
+                SingleSource::File(file) => Ok(SourceReader::Simple(crypt::open(&file)?)),
                 SingleSource::Text(text) => {
                     let cursor = Cursor::new(text);
                     Ok(SourceReader::Simple(Box::new(cursor)))
@@ -224,8 +694,7 @@ impl RunSource {
                         return Err(ReadersError::NoMeta(day));
                     };
 
-                    let file = File::open(&day_info.full)?;
-                    Ok(SourceReader::Simple(Box::new(BufReader::new(file))))
+                    Ok(SourceReader::Simple(crypt::open(&day_info.full)?))
                 }
             },
         }
@@ -280,6 +749,7 @@ fn should_parse_full() {
         },
         part: PartArgs::Both,
         source: RunSource::Single(SingleSource::Full),
+        variant: None,
     });
     // when
     let actual = RunType::parse_from(input);
@@ -298,6 +768,7 @@ fn should_parse_example() {
         },
         part: PartArgs::Both,
         source: RunSource::Example(ExampleSource::ExampleOnly),
+        variant: None,
     });
     // when
     let actual = RunType::parse_from(input);
@@ -316,6 +787,7 @@ fn should_parse_example_full() {
         },
         part: PartArgs::Both,
         source: RunSource::Example(ExampleSource::Main),
+        variant: None,
     });
     // when
     let actual = RunType::parse_from(input);
@@ -334,6 +806,7 @@ fn should_parse_file() {
         },
         part: PartArgs::Both,
         source: RunSource::Single(SingleSource::File(PathBuf::from("\"lol hi.txt\""))),
+        variant: None,
     });
     // when
     let actual = RunType::parse_from(input);
@@ -352,6 +825,7 @@ fn should_parse_text() {
         },
         part: PartArgs::Both,
         source: RunSource::Single(SingleSource::Text("\"123 123\"".to_string())),
+        variant: None,
     });
     // when
     let actual = RunType::parse_from(input);
@@ -370,6 +844,7 @@ fn should_parse_p1() {
         },
         part: PartArgs::P1,
         source: RunSource::Single(SingleSource::Text("\"123 123\"".to_string())),
+        variant: None,
     });
     // when
     let actual = RunType::parse_from(input);
@@ -388,6 +863,7 @@ fn should_parse_p2() {
         },
         part: PartArgs::P2,
         source: RunSource::Single(SingleSource::Text("\"123 123\"".to_string())),
+        variant: None,
     });
     // when
     let actual = RunType::parse_from(input);
@@ -406,9 +882,259 @@ fn should_parse_p12() {
         },
         part: PartArgs::Both,
         source: RunSource::Single(SingleSource::Text("\"123 123\"".to_string())),
+        variant: None,
+    });
+    // when
+    let actual = RunType::parse_from(input);
+    // then
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn should_parse_submit() {
+    // given
+    let input = vec!["app", "submit", "-d", "23", "-y", "2020", "-p", "2"];
+    let expected = RunType::Submit(SubmitArgs {
+        day: Day {
+            day: 23,
+            year: 2020,
+        },
+        part: PartArgs::P2,
+    });
+    // when
+    let actual = RunType::parse_from(input);
+    // then
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn should_reject_bad_loop_durations() {
+    let parse = |secs| RunType::command().try_get_matches_from(["app", "-d", "1", "--loop", secs]);
+    for secs in ["-1", "NaN", "0", "inf"] {
+        // when
+        let result = parse(secs);
+        // then
+        assert!(result.is_err(), "--loop {secs} should be rejected");
+    }
+    assert!(parse("0.5").is_ok());
+}
+
+#[test]
+fn should_parse_loop() {
+    // given
+    let input = vec!["app", "-d", "23", "-y", "2020", "-2", "--loop", "1.5"];
+    let expected = RunType::Profile(ProfileArgs {
+        run: RunArgs {
+            day: Day {
+                day: 23,
+                year: 2020,
+            },
+            part: PartArgs::P2,
+            source: RunSource::Single(SingleSource::Full),
+            variant: None,
+        },
+        duration: Duration::from_millis(1500),
+    });
+    // when
+    let actual = RunType::parse_from(input);
+    // then
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn should_parse_logging() {
+    // given
+    let input = [
+        "app",
+        "-d",
+        "17",
+        "-y",
+        "2024",
+        "-vv",
+        "--log-day",
+        "17-2024",
+    ];
+    let expected = LogArgs {
+        verbosity: 2,
+        days: vec![Day {
+            day: 17,
+            year: 2024,
+        }],
+    };
+    // when
+    let actual = Cli::parse_from(input).log;
+    // then
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn should_parse_gen() {
+    // given
+    let input = vec![
+        "app", "gen", "-d", "9", "-y", "2024", "-s", "7", "-o", "gen.txt",
+    ];
+    let expected = RunType::Gen(GenArgs {
+        day: Day { day: 9, year: 2024 },
+        seed: 7,
+        size: 1000,
+        output: PathBuf::from("gen.txt"),
     });
     // when
     let actual = RunType::parse_from(input);
     // then
     assert_eq!(expected, actual);
 }
+
+#[test]
+fn should_parse_diff() {
+    // given
+    let input = vec!["app", "-d", "20", "-y", "2021", "-e", "--diff"];
+    let expected = RunType::Diff(RunArgs {
+        day: Day {
+            day: 20,
+            year: 2021,
+        },
+        part: PartArgs::Both,
+        source: RunSource::Example(ExampleSource::ExampleOnly),
+        variant: None,
+    });
+    // when
+    let actual = RunType::parse_from(input);
+    // then
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn should_parse_list() {
+    assert_eq!(RunType::List(None), RunType::parse_from(["app", "list"]));
+    assert_eq!(
+        RunType::List(Some(2024)),
+        RunType::parse_from(["app", "list", "-y", "2024"])
+    );
+}
+
+#[test]
+fn should_parse_calendar() {
+    assert_eq!(
+        RunType::Calendar(None),
+        RunType::parse_from(["app", "calendar"])
+    );
+    assert_eq!(
+        RunType::Calendar(Some(2025)),
+        RunType::parse_from(["app", "calendar", "-y", "2025"])
+    );
+}
+
+#[test]
+fn should_parse_crypt() {
+    assert_eq!(
+        RunType::Encrypt(CryptArgs {
+            year: Some(2024),
+            remove: true,
+        }),
+        RunType::parse_from(["app", "encrypt", "-y", "2024", "--remove"])
+    );
+    assert_eq!(
+        RunType::Decrypt(CryptArgs {
+            year: None,
+            remove: false,
+        }),
+        RunType::parse_from(["app", "decrypt"])
+    );
+}
+
+#[test]
+fn should_use_default_year() {
+    // when
+    let cli = Cli::parse_with_year(["app", "-d", "3", "--format", "json"], Some(2025));
+    // then
+    assert_eq!(Some(OutputFormat::Json), cli.format);
+    let RunType::Args(args) = cli.run else {
+        panic!("expected a run")
+    };
+    assert_eq!(Day::new(3, 2025), args.day);
+    assert_eq!(
+        Day::new(3, 2024),
+        match RunType::parse_from(["app", "-d", "3", "-y", "2024"]) {
+            RunType::Args(args) => args.day,
+            run => panic!("expected a run, got {run:?}"),
+        }
+    );
+}
+
+#[test]
+fn should_parse_isolate() {
+    // when
+    let cli = Cli::parse_from(["app", "-a", "--isolate", "--memory-limit", "512"]);
+    let child = RunType::parse_from(["app", isolate::CHILD_FLAG, "17-2024"]);
+    // then
+    assert_eq!(RunType::All, cli.run);
+    assert_eq!(
+        Some(Isolation::new(512, Duration::from_secs(60))),
+        cli.isolate
+    );
+    assert_eq!(RunType::Isolated(Day::new(17, 2024)), child);
+}
+
+#[test]
+fn should_parse_runs() {
+    // when
+    let once = Cli::parse_from(["app", "-a"]);
+    let repeated = Cli::parse_from(["app", "-a", "--runs", "5"]);
+    // then
+    assert_eq!(1, once.runs);
+    assert_eq!(5, repeated.runs);
+}
+
+#[test]
+fn should_parse_import_examples() {
+    assert_eq!(
+        RunType::ImportExamples(ImportArgs {
+            day: Day::new(8, 2025),
+            page: PathBuf::from("day8.html"),
+            block: Some(2),
+        }),
+        RunType::parse_from([
+            "app",
+            "import-examples",
+            "day8.html",
+            "-d",
+            "8",
+            "-y",
+            "2025",
+            "-b",
+            "2"
+        ])
+    );
+}
+
+#[test]
+fn should_parse_visualize() {
+    // given
+    let input = [
+        "app",
+        "-d",
+        "14",
+        "-y",
+        "2024",
+        "--visualize",
+        "--fps",
+        "30",
+        "--export",
+        "frames",
+    ];
+    // when
+    let actual = RunType::parse_from(input);
+    // then
+    let expected = RunType::Visualize(VisualizeArgs {
+        run: RunArgs {
+            day: Day::new(14, 2024),
+            part: PartArgs::Both,
+            source: RunSource::Single(SingleSource::Full),
+            variant: None,
+        },
+        fps: 30,
+        export: Some(PathBuf::from("frames")),
+    });
+    assert_eq!(expected, actual);
+}
@@ -0,0 +1,176 @@
+//! Project settings from `aoc.toml`, so that the binary works from anywhere in the repository.
+
+use crate::crypt;
+use serde::Deserialize;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+use std::{env, fs, io};
+use thiserror::Error;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+/// The meta file inside the data directory.
+pub const META_FILE: &str = "meta.json";
+const DEFAULT_DATA_DIR: &str = "data";
+const DEFAULT_PLUGIN_DIR: &str = "plugins";
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("{0}: {1}")]
+    Parse(PathBuf, toml::de::Error),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+/// Settings read from `aoc.toml`. Relative paths are resolved against the directory of the file.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The directory with `meta.json`, `data` next to the config file if not set
+    pub data_dir: Option<PathBuf>,
+    /// The year to use when a day is given without one
+    pub year: Option<i32>,
+    pub format: OutputFormat,
+    /// A file holding the session token for submitting, used when `AOC_SESSION` isn't set
+    pub session_file: Option<PathBuf>,
+    /// The file with the key for encrypted inputs, `.input-key` next to the config file if not set
+    pub key_file: Option<PathBuf>,
+    /// Whether `--all` reuses results while neither the solver nor the input changed
+    pub cache: bool,
+    /// The directory with solver plugins, `plugins` next to the config file if not set
+    pub plugin_dir: Option<PathBuf>,
+    /// How long a day may take when the meta doesn't give it a budget, in milliseconds
+    pub budget_ms: Option<u64>,
+}
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    /// One JSON value per result, for scripts
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            s => Err(format!("expected text or json, got {s:?}")),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl Config {
+    /// Reads the config from `AOC_CONFIG`, or from the first `aoc.toml` in the current directory
+    /// or its parents, then applies `AOC_DATA_DIR` and `AOC_PLUGIN_DIR`.
+    ///
+    /// Without a config file everything is relative to the current directory.
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = match env::var_os("AOC_CONFIG") {
+            Some(path) => Some(PathBuf::from(path)),
+            None => find(&env::current_dir()?),
+        };
+        let mut config = match path {
+            Some(path) => Self::read(&path)?,
+            None => Self::default(),
+        };
+
+        if let Some(dir) = env::var_os("AOC_DATA_DIR") {
+            config.data_dir = Some(dir.into());
+        }
+        if let Some(dir) = env::var_os("AOC_PLUGIN_DIR") {
+            config.plugin_dir = Some(dir.into());
+        }
+        Ok(config)
+    }
+
+    pub fn read(path: &Path) -> Result<Self, ConfigError> {
+        let text = fs::read_to_string(path)?;
+        let config: Self =
+            toml::from_str(&text).map_err(|e| ConfigError::Parse(path.to_path_buf(), e))?;
+        Ok(config.relative_to(path.parent().unwrap_or(Path::new(""))))
+    }
+
+    fn relative_to(self, dir: &Path) -> Self {
+        let resolve = |path: Option<PathBuf>| path.map(|p| dir.join(p));
+        Self {
+            data_dir: Some(resolve(self.data_dir).unwrap_or_else(|| dir.join(DEFAULT_DATA_DIR))),
+            session_file: resolve(self.session_file),
+            key_file: Some(resolve(self.key_file).unwrap_or_else(|| dir.join(crypt::KEY_FILE))),
+            plugin_dir: Some(
+                resolve(self.plugin_dir).unwrap_or_else(|| dir.join(DEFAULT_PLUGIN_DIR)),
+            ),
+            ..self
+        }
+    }
+
+    pub fn data_dir(&self) -> PathBuf {
+        self.data_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_DATA_DIR))
+    }
+
+    pub fn key_file(&self) -> PathBuf {
+        self.key_file
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(crypt::KEY_FILE))
+    }
+
+    pub fn plugin_dir(&self) -> PathBuf {
+        self.plugin_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_PLUGIN_DIR))
+    }
+
+    pub fn default_budget(&self) -> Option<Duration> {
+        self.budget_ms.map(Duration::from_millis)
+    }
+
+    pub fn meta_path(&self) -> PathBuf {
+        self.data_dir().join(META_FILE)
+    }
+}
+
+/// The closest config file in `dir` or one of its parents.
+fn find(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join(CONFIG_FILE))
+        .find(|path| path.is_file())
+}
+
+#[test]
+fn should_resolve_paths_relative_to_config() {
+    // given
+    let text = r#"
+        year = 2024
+        format = "json"
+        session_file = "secrets/session"
+    "#;
+    // when
+    let config = toml::from_str::<Config>(text)
+        .unwrap()
+        .relative_to(Path::new("/repo"));
+    // then
+    assert_eq!(Some(2024), config.year);
+    assert_eq!(OutputFormat::Json, config.format);
+    assert_eq!(Path::new("/repo/data/meta.json"), config.meta_path());
+    assert_eq!(Path::new("/repo/.input-key"), config.key_file());
+    assert_eq!(
+        Some(PathBuf::from("/repo/secrets/session")),
+        config.session_file
+    );
+}
@@ -0,0 +1,289 @@
+use crate::day::Day;
+use std::fmt::{Arguments, Display, Formatter};
+use std::io::Write;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+static CURRENT: RwLock<Option<Arc<Context>>> = RwLock::new(None);
+
+/// The verbosity of the installed context, so that log messages can be skipped without taking
+/// the lock on [`CURRENT`].
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+/// How often a progress line is redrawn at most.
+const REDRAW: Duration = Duration::from_millis(100);
+
+/// Settings the runner hands to solvers while they run.
+///
+/// Solvers get at it with [`Context::current`], since their signatures only take the input.
+#[derive(Debug, Default, Clone)]
+pub struct Context {
+    show_progress: bool,
+    verbosity: u8,
+    log_days: Vec<Day>,
+    day: Option<Day>,
+    input: Input,
+}
+
+/// Which input the solvers are being given, for the few whose parameters differ between the
+/// example and the full input.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum Input {
+    Example,
+    Full,
+    /// A file or text given on the command line, or an input the runner doesn't know about
+    #[default]
+    Other,
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Input::Example => write!(f, "example"),
+            Input::Full => write!(f, "full"),
+            Input::Other => write!(f, "other"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Level {
+    /// Shown with `-v`
+    Debug = 1,
+    /// Shown with `-vv`
+    Trace = 2,
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether progress handles draw a line on stderr.
+    pub fn with_progress(mut self, show_progress: bool) -> Self {
+        self.show_progress = show_progress;
+        self
+    }
+
+    /// Shows log messages up to this level, only for `days` unless it is empty.
+    pub fn with_logging(mut self, verbosity: u8, days: Vec<Day>) -> Self {
+        self.verbosity = verbosity;
+        self.log_days = days;
+        self
+    }
+
+    /// Makes this the context for every solver run from now on.
+    pub fn install(self) {
+        VERBOSITY.store(self.verbosity, Ordering::Relaxed);
+        *CURRENT.write().unwrap() = Some(Arc::new(self));
+    }
+
+    /// Marks `day` as the one being run, so log messages are attributed to it.
+    pub fn enter(day: Day) {
+        let mut current = CURRENT.write().unwrap();
+        let mut context = current.as_deref().cloned().unwrap_or_default();
+        context.day = Some(day);
+        *current = Some(Arc::new(context));
+    }
+
+    /// Marks `input` as the one solvers are given from now on.
+    pub fn enter_input(input: Input) {
+        let mut current = CURRENT.write().unwrap();
+        let mut context = current.as_deref().cloned().unwrap_or_default();
+        context.input = input;
+        *current = Some(Arc::new(context));
+    }
+
+    pub fn input(&self) -> Input {
+        self.input
+    }
+
+    /// The installed context, or a silent one if the runner didn't install any.
+    pub fn current() -> Arc<Context> {
+        CURRENT
+            .read()
+            .unwrap()
+            .clone()
+            .unwrap_or_else(|| Arc::new(Context::default()))
+    }
+
+    /// Whether `level` can be logged at all, without looking at the current day.
+    pub fn level_enabled(level: Level) -> bool {
+        VERBOSITY.load(Ordering::Relaxed) >= level as u8
+    }
+
+    pub fn log_enabled(&self, level: Level) -> bool {
+        self.verbosity >= level as u8
+            && (self.log_days.is_empty()
+                || self.day.is_some_and(|day| self.log_days.contains(&day)))
+    }
+
+    /// Writes a message to stderr if `level` is enabled for the current day.
+    ///
+    /// Solvers should use the [`debug`] and [`trace`] macros instead, which don't evaluate their
+    /// arguments when the level is disabled.
+    pub fn log(&self, level: Level, args: Arguments) {
+        if !self.log_enabled(level) {
+            return;
+        }
+        match self.day {
+            Some(day) => eprintln!("[{day}] {args}"),
+            None => eprintln!("{args}"),
+        }
+    }
+
+    /// Starts reporting progress under `label`; the line is cleared when the handle is dropped.
+    pub fn progress(&self, label: &'static str) -> Progress {
+        Progress {
+            label,
+            count: 0,
+            total: None,
+            visible: self.show_progress,
+            last_draw: None,
+        }
+    }
+}
+
+pub struct Progress {
+    label: &'static str,
+    count: u64,
+    total: Option<u64>,
+    visible: bool,
+    last_draw: Option<Instant>,
+}
+
+impl Progress {
+    /// Shows the count as a percentage of `total`.
+    pub fn with_total(mut self, total: u64) -> Self {
+        self.total = Some(total);
+        self
+    }
+
+    pub fn set(&mut self, count: u64) {
+        self.count = count;
+        self.draw();
+    }
+
+    pub fn inc(&mut self, n: u64) {
+        self.set(self.count + n);
+    }
+
+    fn draw(&mut self) {
+        if !self.visible || self.last_draw.is_some_and(|t| t.elapsed() < REDRAW) {
+            return;
+        }
+        self.last_draw = Some(Instant::now());
+
+        let mut stderr = std::io::stderr().lock();
+        let _ = write!(stderr, "\r\x1b[2K{self}");
+        let _ = stderr.flush();
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if self.last_draw.is_some() {
+            eprint!("\r\x1b[2K");
+        }
+    }
+}
+
+impl Display for Progress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.total {
+            Some(total) => write!(
+                f,
+                "{}: {}/{} ({:.1}%)",
+                self.label,
+                self.count,
+                total,
+                self.count as f64 / total.max(1) as f64 * 100.0
+            ),
+            None => write!(f, "{}: {}", self.label, self.count),
+        }
+    }
+}
+
+/// Logs a message for the current day, shown with `-v`.
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::context::Context::level_enabled($crate::context::Level::Debug) {
+            let context = $crate::context::Context::current();
+            if context.log_enabled($crate::context::Level::Debug) {
+                context.log($crate::context::Level::Debug, format_args!($($arg)*))
+            }
+        }
+    };
+}
+
+/// Logs a message for the current day, shown with `-vv`.
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::context::Context::level_enabled($crate::context::Level::Trace) {
+            let context = $crate::context::Context::current();
+            if context.log_enabled($crate::context::Level::Trace) {
+                context.log($crate::context::Level::Trace, format_args!($($arg)*))
+            }
+        }
+    };
+}
+
+pub(crate) use {debug, trace};
+
+#[test]
+fn should_format_progress() {
+    // given
+    let context = Context::new();
+    // when
+    let mut progress = context.progress("prefixes").with_total(8);
+    progress.inc(2);
+    let mut counter = context.progress("a");
+    counter.set(100000);
+    // then
+    assert_eq!("prefixes: 2/8 (25.0%)", progress.to_string());
+    assert_eq!("a: 100000", counter.to_string());
+}
+
+#[test]
+fn should_filter_logs_by_level_and_day() {
+    // given
+    let day = Day {
+        day: 17,
+        year: 2024,
+    };
+    let other = Day {
+        day: 21,
+        year: 2024,
+    };
+    let context = Context::new().with_logging(1, vec![day]);
+    // when
+    let entered = Context {
+        day: Some(day),
+        ..context.clone()
+    };
+    let entered_other = Context {
+        day: Some(other),
+        ..context
+    };
+    // then
+    assert!(entered.log_enabled(Level::Debug));
+    assert!(!entered.log_enabled(Level::Trace));
+    assert!(!entered_other.log_enabled(Level::Debug));
+}
+
+#[test]
+fn should_not_format_disabled_logs() {
+    // given
+    let mut formatted = 0;
+    let mut expensive = || {
+        formatted += 1;
+        "path"
+    };
+    // when
+    debug!("{}", expensive());
+    trace!("{}", expensive());
+    // then
+    assert_eq!(0, formatted);
+    assert!(!Context::level_enabled(Level::Debug));
+}
@@ -0,0 +1,177 @@
+//! Encrypted-at-rest inputs, so that the puzzle inputs can be versioned without publishing them.
+//!
+//! An input `full-1-24.txt` may instead be stored as `full-1-24.txt.enc`, which is decrypted
+//! when opened if the plain file doesn't exist.
+
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use std::ffi::OsString;
+use std::io::{BufRead, BufReader, Cursor};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::{env, fs, io};
+use thiserror::Error;
+
+/// Hex-encoded 32 byte key.
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+/// File holding the key when [`KEY_VAR`] isn't set, unless [`set_key_file`] or
+/// `AOC_INPUT_KEY_FILE` point elsewhere.
+pub const KEY_FILE: &str = ".input-key";
+pub const EXTENSION: &str = "enc";
+
+const MAGIC: &[u8] = b"AOCENC1\n";
+const NONCE_LEN: usize = 12;
+
+static CONFIGURED_KEY_FILE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Sets where the key file is when `AOC_INPUT_KEY_FILE` isn't set, instead of [`KEY_FILE`] in
+/// the current directory. Only the first call has an effect.
+pub fn set_key_file(path: PathBuf) {
+    let _ = CONFIGURED_KEY_FILE.set(path);
+}
+
+fn key_file() -> PathBuf {
+    match env::var_os("AOC_INPUT_KEY_FILE") {
+        Some(path) => path.into(),
+        None => CONFIGURED_KEY_FILE
+            .get()
+            .cloned()
+            .unwrap_or_else(|| PathBuf::from(KEY_FILE)),
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum CryptError {
+    #[error("no input key, set {KEY_VAR} or write it to {}", .0.display())]
+    NoKey(PathBuf),
+    #[error("the input key should be 64 hex characters")]
+    InvalidKey,
+    #[error("not an encrypted input")]
+    NotEncrypted,
+    #[error("could not decrypt, the key may be wrong")]
+    Decrypt,
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+pub struct Key(ChaCha20Poly1305);
+
+impl Key {
+    pub fn new(bytes: [u8; 32]) -> Self {
+        Self(ChaCha20Poly1305::new(&bytes.into()))
+    }
+
+    /// Reads the key from [`KEY_VAR`], or from the key file.
+    pub fn load() -> Result<Self, CryptError> {
+        let hex = match env::var(KEY_VAR) {
+            Ok(hex) => hex,
+            Err(_) => {
+                let path = key_file();
+                match fs::read_to_string(&path) {
+                    Ok(hex) => hex,
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                        return Err(CryptError::NoKey(path))
+                    }
+                    Err(e) => return Err(e.into()),
+                }
+            }
+        };
+        Self::from_hex(hex.trim())
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self, CryptError> {
+        let mut bytes = [0; 32];
+        hex::decode_to_slice(hex, &mut bytes).map_err(|_| CryptError::InvalidKey)?;
+        Ok(Self::new(bytes))
+    }
+
+    /// Encrypts with a random nonce, which is stored in front of the ciphertext.
+    pub fn encrypt(&self, plain: &[u8]) -> Vec<u8> {
+        let nonce: [u8; NONCE_LEN] = rand::random();
+        let ciphertext = self
+            .0
+            .encrypt(Nonce::from_slice(&nonce), plain)
+            .expect("encrypting into a Vec doesn't fail");
+
+        [MAGIC, &nonce, &ciphertext].concat()
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, CryptError> {
+        let data = data.strip_prefix(MAGIC).ok_or(CryptError::NotEncrypted)?;
+        if data.len() < NONCE_LEN {
+            return Err(CryptError::NotEncrypted);
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        self.0
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| CryptError::Decrypt)
+    }
+}
+
+/// Where the encrypted version of `path` is stored.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(".");
+    name.push(EXTENSION);
+    PathBuf::from(name)
+}
+
+/// The file actually holding the contents of `path`: itself, or its encrypted version if only
+/// that exists.
+pub fn stored_path(path: &Path) -> PathBuf {
+    let encrypted = encrypted_path(path);
+    if !path.exists() && encrypted.exists() {
+        encrypted
+    } else {
+        path.to_path_buf()
+    }
+}
+
+/// Whether `path` or its encrypted version exists.
+pub fn exists(path: &Path) -> bool {
+    stored_path(path).exists()
+}
+
+/// Reads `path`, decrypting its encrypted version if only that exists.
+pub fn read(path: &Path) -> io::Result<Vec<u8>> {
+    let stored = stored_path(path);
+    if stored == path {
+        return fs::read(path);
+    }
+
+    let decrypt = || Key::load()?.decrypt(&fs::read(&stored)?);
+    decrypt().map_err(|e| match e {
+        CryptError::Io(e) => e,
+        e => io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {e}", stored.display()),
+        ),
+    })
+}
+
+/// Opens `path` like [`read`], without reading plain files up front.
+pub fn open(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    if path.exists() {
+        return Ok(Box::new(BufReader::new(fs::File::open(path)?)));
+    }
+    Ok(Box::new(Cursor::new(read(path)?)))
+}
+
+#[test]
+fn should_decrypt_encrypted_input() {
+    // given
+    let key = Key::from_hex(&"2a".repeat(32)).unwrap();
+    let input = b"1   2\n3   4\n";
+    // when
+    let encrypted = key.encrypt(input);
+    // then
+    assert!(!encrypted.windows(input.len()).any(|w| w == input));
+    assert_eq!(input.to_vec(), key.decrypt(&encrypted).unwrap());
+
+    let other = Key::from_hex(&"2b".repeat(32)).unwrap();
+    assert!(matches!(
+        other.decrypt(&encrypted),
+        Err(CryptError::Decrypt)
+    ));
+    assert!(matches!(key.decrypt(input), Err(CryptError::NotEncrypted)));
+}
@@ -1,21 +1,57 @@
-use lazy_static::lazy_static;
+use crate::external::ExternalCommand;
+use crate::plugin::{self, Plugin};
+use crate::visualize::Visualize;
+use indexmap::IndexMap;
+use rand::rngs::StdRng;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
-use std::io::BufRead;
-use std::path::PathBuf;
+use std::fs::File;
+use std::io;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::Duration;
 
-pub trait CombinedSolver: 'static + Sync {
+pub trait CombinedSolver: 'static + Send + Sync {
     fn solve(&self, input: Box<dyn BufRead>) -> anyhow::Result<(String, String)>;
+
+    /// False if part 1 is only a placeholder.
+    fn has_part_1(&self) -> bool {
+        true
+    }
+
+    /// False if part 2 is only a placeholder.
+    fn has_part_2(&self) -> bool {
+        true
+    }
+
+    /// Runs both parts, giving [`AnswerValue::Unimplemented`] for a part without an answer, and
+    /// not running at all if both are placeholders.
+    fn answer(&self, input: Box<dyn BufRead>) -> anyhow::Result<(AnswerValue, AnswerValue)> {
+        let implemented = [self.has_part_1(), self.has_part_2()];
+        if implemented == [false, false] {
+            return Ok((AnswerValue::Unimplemented, AnswerValue::Unimplemented));
+        }
+        let (p_1, p_2) = self.solve(input)?;
+        let value = |answer: String, implemented| {
+            if implemented {
+                answer.into()
+            } else {
+                AnswerValue::Unimplemented
+            }
+        };
+        Ok((value(p_1, implemented[0]), value(p_2, implemented[1])))
+    }
 }
 
 impl<A1, A2, F> CombinedSolver for F
 where
     A1: Display,
     A2: Display,
-    F: Fn(Box<dyn BufRead>) -> anyhow::Result<(A1, A2)> + 'static + Sync,
+    F: Fn(Box<dyn BufRead>) -> anyhow::Result<(A1, A2)> + 'static + Send + Sync,
 {
     fn solve(&self, input: Box<dyn BufRead>) -> anyhow::Result<(String, String)> {
         let (a, b) = self(input)?;
@@ -23,17 +59,43 @@ where
     }
 }
 
-pub trait SeparatedSolver: 'static + Sync {
+pub trait SeparatedSolver: 'static + Send + Sync {
     fn part_1(&self, input: Box<dyn BufRead>) -> anyhow::Result<String>;
     fn part_2(&self, input: Box<dyn BufRead>) -> anyhow::Result<String>;
+
+    /// False if part 1 is only a placeholder.
+    fn has_part_1(&self) -> bool {
+        true
+    }
+
+    /// False if part 2 is only a placeholder.
+    fn has_part_2(&self) -> bool {
+        true
+    }
+
+    /// Runs part 1, or gives [`AnswerValue::Unimplemented`] without running a placeholder.
+    fn answer_part_1(&self, input: Box<dyn BufRead>) -> anyhow::Result<AnswerValue> {
+        if !self.has_part_1() {
+            return Ok(AnswerValue::Unimplemented);
+        }
+        self.part_1(input).map(AnswerValue::from)
+    }
+
+    /// Runs part 2, or gives [`AnswerValue::Unimplemented`] without running a placeholder.
+    fn answer_part_2(&self, input: Box<dyn BufRead>) -> anyhow::Result<AnswerValue> {
+        if !self.has_part_2() {
+            return Ok(AnswerValue::Unimplemented);
+        }
+        self.part_2(input).map(AnswerValue::from)
+    }
 }
 
 impl<A1, A2, F1, F2> SeparatedSolver for (F1, F2)
 where
     A1: Display,
     A2: Display,
-    F1: Fn(Box<dyn BufRead>) -> anyhow::Result<A1> + 'static + Sync,
-    F2: Fn(Box<dyn BufRead>) -> anyhow::Result<A2> + 'static + Sync,
+    F1: Fn(Box<dyn BufRead>) -> anyhow::Result<A1> + 'static + Send + Sync,
+    F2: Fn(Box<dyn BufRead>) -> anyhow::Result<A2> + 'static + Send + Sync,
 {
     fn part_1(&self, input: Box<dyn BufRead>) -> anyhow::Result<String> {
         self.0(input).map(|x| x.to_string())
@@ -47,24 +109,32 @@ where
 impl<A, F> SeparatedSolver for (F, ())
 where
     A: Display,
-    F: Fn(Box<dyn BufRead>) -> anyhow::Result<A> + 'static + Sync,
+    F: Fn(Box<dyn BufRead>) -> anyhow::Result<A> + 'static + Send + Sync,
 {
     fn part_1(&self, input: Box<dyn BufRead>) -> anyhow::Result<String> {
         self.0(input).map(|x| x.to_string())
     }
 
     fn part_2(&self, _input: Box<dyn BufRead>) -> anyhow::Result<String> {
-        Ok("todo".to_string())
+        anyhow::bail!("part 2 is not implemented")
+    }
+
+    fn has_part_2(&self) -> bool {
+        false
     }
 }
 
 impl<A, F> SeparatedSolver for ((), F)
 where
     A: Display,
-    F: Fn(Box<dyn BufRead>) -> anyhow::Result<A> + 'static + Sync,
+    F: Fn(Box<dyn BufRead>) -> anyhow::Result<A> + 'static + Send + Sync,
 {
     fn part_1(&self, _input: Box<dyn BufRead>) -> anyhow::Result<String> {
-        Ok("todo".to_string())
+        anyhow::bail!("part 1 is not implemented")
+    }
+
+    fn has_part_1(&self) -> bool {
+        false
     }
 
     fn part_2(&self, input: Box<dyn BufRead>) -> anyhow::Result<String> {
@@ -87,33 +157,254 @@ impl Solver {
     pub fn separated(solver: impl SeparatedSolver) -> Self {
         Self::Separated(Box::new(solver))
     }
+
+    /// Which of the two parts are actually solved rather than placeholders.
+    pub fn implemented_parts(&self) -> [bool; 2] {
+        match self {
+            Solver::Combined(solver) => [solver.has_part_1(), solver.has_part_2()],
+            Solver::Separated(solver) => [solver.has_part_1(), solver.has_part_2()],
+        }
+    }
+}
+
+/// Produces random valid inputs for a day, for stress-testing its solver.
+///
+/// `size` is day-specific, e.g. the number of lines.
+pub trait Generator: 'static + Send + Sync {
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String;
+}
+
+impl<F> Generator for F
+where
+    F: Fn(&mut StdRng, usize) -> String + 'static + Send + Sync,
+{
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        self(rng, size)
+    }
 }
 
 pub type Result = anyhow::Result<Answer>;
 
+/// A single part's answer, normalized so that formatting differences don't matter.
+///
+/// Stored as a plain string in the manifest, where [`AnswerValue::Unimplemented`] has no place
+/// since it is only produced by the runner for placeholder parts.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum AnswerValue {
+    Int(i128),
+    Text(String),
+    /// Multi-line answers, like letters drawn in a grid
+    Grid(Vec<String>),
+    /// The solver only has a placeholder for this part
+    Unimplemented,
+}
+
+impl AnswerValue {
+    pub fn as_int(&self) -> Option<i128> {
+        match self {
+            AnswerValue::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, AnswerValue::Grid(_))
+    }
+
+    pub fn is_implemented(&self) -> bool {
+        !matches!(self, AnswerValue::Unimplemented)
+    }
+
+    /// Displays the value with each line of a grid on its own line, indented by `indent`.
+    pub fn indented(&self, indent: usize) -> Indented<'_, Self> {
+        Indented(self, indent)
+    }
+}
+
+impl From<&str> for AnswerValue {
+    fn from(value: &str) -> Self {
+        let lines = value
+            .lines()
+            .map(str::trim_end)
+            .skip_while(|l| l.is_empty())
+            .collect::<Vec<_>>();
+        let end = lines
+            .iter()
+            .rposition(|l| !l.is_empty())
+            .map_or(0, |i| i + 1);
+
+        match &lines[..end] {
+            [] => AnswerValue::Text(String::new()),
+            [line] => {
+                let line = line.trim();
+                match line.parse::<i128>() {
+                    Ok(i) => AnswerValue::Int(i),
+                    Err(_) => AnswerValue::Text(line.to_string()),
+                }
+            }
+            lines => AnswerValue::Grid(lines.iter().map(|l| l.to_string()).collect()),
+        }
+    }
+}
+
+impl From<String> for AnswerValue {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl From<AnswerValue> for String {
+    fn from(value: AnswerValue) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for AnswerValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswerValue::Int(i) => write!(f, "{}", i),
+            AnswerValue::Text(s) => write!(f, "{}", s),
+            AnswerValue::Grid(lines) => write!(f, "{}", lines.join("\n")),
+            AnswerValue::Unimplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+pub struct Indented<'a, T>(&'a T, usize);
+
+impl Display for Indented<'_, AnswerValue> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            AnswerValue::Grid(lines) => {
+                for line in lines {
+                    write!(f, "\n{:indent$}{}", "", line, indent = self.1)?;
+                }
+                Ok(())
+            }
+            value => write!(f, "{}", value),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Answer {
-    P1(String),
-    P2(String),
-    Both(String, String),
+    P1(AnswerValue),
+    P2(AnswerValue),
+    Both(AnswerValue, AnswerValue),
+}
+
+/// How an answer compares to the expected one.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    /// Every part with an expected answer was answered with it
+    Passed,
+    /// A part with an expected answer got a different one, or none
+    Wrong,
+    /// The part is only a placeholder, so it couldn't be compared
+    Unimplemented(u8),
 }
 
 impl Answer {
-    pub fn passed(&self, other: &Answer) -> bool {
-        match (self, other) {
-            (Answer::Both(s_1, s_2), Answer::Both(p_1, p_2)) => s_1 == p_1 && s_2 == p_2,
-            (Answer::P1(p1), Answer::P1(p2)) => p1 == p2,
-            (Answer::P1(p1), Answer::Both(p2, _)) => p1 == p2,
-            (Answer::P2(p1), Answer::Both(_, p2)) => p1 == p2,
-            (Answer::P1(_), Answer::P2(_)) => true,
-            _ => other.passed(self),
+    /// Compares the parts that have an `expected` answer, a wrong part outweighing a placeholder.
+    pub fn check(&self, expected: &Answer) -> Verdict {
+        let part = |level: u8, actual: Option<&AnswerValue>, expected: Option<&AnswerValue>| match (
+            actual, expected,
+        ) {
+            (_, None | Some(AnswerValue::Unimplemented)) => Verdict::Passed,
+            (Some(AnswerValue::Unimplemented), _) => Verdict::Unimplemented(level),
+            (actual, expected) if actual == expected => Verdict::Passed,
+            _ => Verdict::Wrong,
+        };
+        match (
+            part(1, self.part_1(), expected.part_1()),
+            part(2, self.part_2(), expected.part_2()),
+        ) {
+            (Verdict::Wrong, _) | (_, Verdict::Wrong) => Verdict::Wrong,
+            (Verdict::Unimplemented(level), _) | (_, Verdict::Unimplemented(level)) => {
+                Verdict::Unimplemented(level)
+            }
+            _ => Verdict::Passed,
+        }
+    }
+
+    pub fn passed(&self, expected: &Answer) -> bool {
+        self.check(expected) == Verdict::Passed
+    }
+
+    #[inline]
+    pub fn p1(p1: impl Into<AnswerValue>) -> Self {
+        Answer::P1(p1.into())
+    }
+
+    #[inline]
+    pub fn p2(p2: impl Into<AnswerValue>) -> Self {
+        Answer::P2(p2.into())
+    }
+
+    #[inline]
+    pub fn both(p1: impl Into<AnswerValue>, p2: impl Into<AnswerValue>) -> Self {
+        Answer::Both(p1.into(), p2.into())
+    }
+
+    pub fn indented(&self, indent: usize) -> Indented<'_, Self> {
+        Indented(self, indent)
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        self.part_1().is_some_and(AnswerValue::is_multiline)
+            || self.part_2().is_some_and(AnswerValue::is_multiline)
+    }
+
+    pub fn from_parts(p1: Option<AnswerValue>, p2: Option<AnswerValue>) -> Option<Self> {
+        match (p1, p2) {
+            (Some(p1), Some(p2)) => Some(Answer::Both(p1, p2)),
+            (Some(p1), None) => Some(Answer::P1(p1)),
+            (None, Some(p2)) => Some(Answer::P2(p2)),
+            (None, None) => None,
+        }
+    }
+
+    pub fn part_1(&self) -> Option<&AnswerValue> {
+        match self {
+            Answer::P1(p1) | Answer::Both(p1, _) => Some(p1),
+            Answer::P2(_) => None,
+        }
+    }
+
+    pub fn part_2(&self) -> Option<&AnswerValue> {
+        match self {
+            Answer::P2(p2) | Answer::Both(_, p2) => Some(p2),
+            Answer::P1(_) => None,
         }
     }
 }
 
 impl Display for Answer {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
+        write!(f, "{}", self.indented(0))
+    }
+}
+
+impl Display for Indented<'_, Answer> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Indented(answer, indent) = *self;
+        // multi-line answers get each part on its own line, with grids indented below
+        if answer.is_multiline() {
+            let mut first = true;
+            for (name, part) in [("P1", answer.part_1()), ("P2", answer.part_2())] {
+                let Some(part) = part else { continue };
+                if !first {
+                    write!(f, "\n{:indent$}", "")?;
+                }
+                let space = if part.is_multiline() { "" } else { " " };
+                write!(f, "{}:{}{}", name, space, part.indented(indent + 2))?;
+                first = false;
+            }
+            return Ok(());
+        }
+
+        match answer {
             Answer::P1(p1) => write!(f, "P1: {}", p1),
             Answer::P2(p2) => write!(f, "P2: {}", p2),
             Answer::Both(p1, p2) => write!(f, "P1: {}, P2: {}", p1, p2),
@@ -129,9 +420,9 @@ pub enum Example {
     },
     Multi {
         path_1: PathBuf,
-        expected_answer_1: String,
+        expected_answer_1: AnswerValue,
         path_2: PathBuf,
-        expected_answer_2: String,
+        expected_answer_2: AnswerValue,
     },
 }
 
@@ -139,6 +430,140 @@ pub enum Example {
 pub struct DayInfo {
     pub full: PathBuf,
     pub example: Example,
+    /// Answers for the full input that were accepted on submission
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verified: Option<Answer>,
+    /// Answers for the full input that were rejected on submission
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rejected: Vec<Rejected>,
+    /// A program to solve the day with when there's no solver for it in Rust
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<ExternalCommand>,
+    /// How long both parts may take on the full input, in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget_ms: Option<u64>,
+}
+
+impl DayInfo {
+    /// The day's own budget, or else `default`.
+    pub fn budget(&self, default: Option<Duration>) -> Option<Duration> {
+        self.budget_ms.map(Duration::from_millis).or(default)
+    }
+
+    pub fn reject(&mut self, part: u8, answer: AnswerValue, hint: Option<Hint>) {
+        if !self
+            .rejected
+            .iter()
+            .any(|r| r.part == part && r.answer == answer)
+        {
+            self.rejected.push(Rejected { part, answer, hint });
+        }
+    }
+
+    /// Checks an answer for the full input against the rejected answers for the same part.
+    pub fn check(&self, part: u8, answer: &AnswerValue) -> Option<AnswerWarning> {
+        let rejected = self.rejected.iter().filter(|r| r.part == part);
+
+        if let Some(r) = rejected.clone().find(|r| &r.answer == answer) {
+            return Some(AnswerWarning::KnownWrong {
+                part,
+                answer: answer.clone(),
+                hint: r.hint,
+            });
+        }
+
+        let value = answer.as_int()?;
+        let bound = |hint: Hint| {
+            rejected
+                .clone()
+                .filter(move |r| r.hint == Some(hint))
+                .filter_map(|r| r.answer.as_int())
+        };
+        let low = bound(Hint::TooLow).max();
+        let high = bound(Hint::TooHigh).min();
+
+        let outside = low.is_some_and(|l| value <= l) || high.is_some_and(|h| value >= h);
+        outside.then(|| AnswerWarning::OutOfBounds {
+            part,
+            answer: answer.clone(),
+            low,
+            high,
+        })
+    }
+
+    pub fn check_answer(&self, answer: &Answer) -> Vec<AnswerWarning> {
+        [(1, answer.part_1()), (2, answer.part_2())]
+            .into_iter()
+            .filter_map(|(part, a)| self.check(part, a?))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Rejected {
+    pub part: u8,
+    pub answer: AnswerValue,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum AnswerWarning {
+    KnownWrong {
+        part: u8,
+        answer: AnswerValue,
+        hint: Option<Hint>,
+    },
+    OutOfBounds {
+        part: u8,
+        answer: AnswerValue,
+        low: Option<i128>,
+        high: Option<i128>,
+    },
+}
+
+impl Display for AnswerWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswerWarning::KnownWrong {
+                part,
+                answer,
+                hint: Some(hint),
+            } => write!(f, "part {part} answer {answer} was rejected as {hint}"),
+            AnswerWarning::KnownWrong { part, answer, .. } => {
+                write!(f, "part {part} answer {answer} was rejected")
+            }
+            AnswerWarning::OutOfBounds {
+                part,
+                answer,
+                low,
+                high,
+            } => {
+                write!(f, "part {part} answer {answer} is outside known bounds (")?;
+                match (low, high) {
+                    (Some(l), Some(h)) => write!(f, "{l} < x < {h})"),
+                    (Some(l), None) => write!(f, "x > {l})"),
+                    (None, Some(h)) => write!(f, "x < {h})"),
+                    (None, None) => write!(f, "none)"),
+                }
+            }
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -184,34 +609,217 @@ impl FromStr for Day {
     }
 }
 
+/// Puts arrays of plain values, like answers and commands, on one line in pretty-printed JSON, as
+/// the manifest is written by hand, so that saving it only changes what was recorded.
+fn inline_arrays(pretty: &str) -> String {
+    let lines = pretty.lines().collect::<Vec<_>>();
+    let mut out = Vec::with_capacity(lines.len());
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        i += 1;
+        if !line.ends_with('[') {
+            out.push(line.to_string());
+            continue;
+        }
+        // strings can't contain line breaks, so an element opening an object or array is the
+        // only kind of line that ends with a bracket
+        let Some(len) = lines[i..]
+            .iter()
+            .position(|l| l.trim_start().starts_with(']') || l.ends_with(['[', '{']))
+        else {
+            out.push(line.to_string());
+            continue;
+        };
+        let end = lines[i + len];
+        if !end.trim_start().starts_with(']') {
+            out.push(line.to_string());
+            continue;
+        }
+        let values = lines[i..i + len]
+            .iter()
+            .map(|l| l.trim())
+            .collect::<Vec<_>>();
+        out.push(format!("{line}{}{}", values.join(" "), end.trim_start()));
+        i += len + 1;
+    }
+    out.join("\n")
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
-pub struct DaysMeta(pub HashMap<String, DayInfo>);
+pub struct DaysMeta(pub IndexMap<String, DayInfo>);
 
 impl DaysMeta {
     pub fn get_day(&self, day: Day) -> Option<&DayInfo> {
         self.0.get(&day.to_string())
     }
+
+    pub fn get_day_mut(&mut self, day: Day) -> Option<&mut DayInfo> {
+        self.0.get_mut(&day.to_string())
+    }
+
+    /// Reads the meta from `path`; invalid JSON is reported as [`io::ErrorKind::InvalidData`].
+    ///
+    /// Input paths in the file are relative to the file itself, and commands are run from its
+    /// directory.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut meta: Self = serde_json::from_reader(reader)?;
+        let dir = path.parent().unwrap_or(Path::new(""));
+        meta.map_paths(|p| dir.join(p));
+        for command in meta.0.values_mut().filter_map(|i| i.command.as_mut()) {
+            command.dir = dir.to_path_buf();
+        }
+        Ok(meta)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let mut meta = self.clone();
+        meta.map_paths(|p| p.strip_prefix(dir).unwrap_or(p).to_path_buf());
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(
+            writer,
+            "{}",
+            inline_arrays(&serde_json::to_string_pretty(&meta)?)
+        )?;
+        writer.flush()
+    }
+
+    fn map_paths(&mut self, f: impl Fn(&Path) -> PathBuf) {
+        for info in self.0.values_mut() {
+            info.full = f(&info.full);
+            match &mut info.example {
+                Example::Single { path, .. } => *path = f(path),
+                Example::Multi { path_1, path_2, .. } => {
+                    *path_1 = f(path_1);
+                    *path_2 = f(path_2);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn should_normalize_answers() {
+    assert_eq!(AnswerValue::Int(42), "  0042 \n".into());
+    assert_eq!(AnswerValue::Text("6,1".to_string()), "6,1".into());
+    assert_eq!(AnswerValue::Text(String::new()), "".into());
+    assert_eq!(
+        AnswerValue::Grid(vec!["#..#".to_string(), "####".to_string()]),
+        "\n#..#  \n####\n\n".into()
+    );
+    assert!(Answer::both("17", "\n#\n#").passed(&Answer::both("017", "#\n#\n")));
+}
+
+#[test]
+fn should_check_rejected_answers() {
+    // given
+    let mut info = DayInfo {
+        full: PathBuf::new(),
+        example: Example::Single {
+            path: PathBuf::new(),
+            expected_answer: Answer::p1("1"),
+        },
+        verified: None,
+        rejected: vec![],
+        command: None,
+        budget_ms: None,
+    };
+    info.reject(1, "100".into(), Some(Hint::TooHigh));
+    info.reject(1, "20".into(), Some(Hint::TooLow));
+    info.reject(1, "50".into(), None);
+    // then
+    assert!(matches!(
+        info.check(1, &"50".into()),
+        Some(AnswerWarning::KnownWrong { hint: None, .. })
+    ));
+    assert!(matches!(
+        info.check(1, &"100".into()),
+        Some(AnswerWarning::KnownWrong {
+            hint: Some(Hint::TooHigh),
+            ..
+        })
+    ));
+    assert_eq!(
+        info.check(1, &"120".into()),
+        Some(AnswerWarning::OutOfBounds {
+            part: 1,
+            answer: AnswerValue::Int(120),
+            low: Some(20),
+            high: Some(100),
+        })
+    );
+    assert!(info.check(1, &"10".into()).is_some());
+    assert_eq!(info.check(1, &" 060".into()), None);
+    assert_eq!(info.check(2, &"120".into()), None);
 }
 
 #[derive(Default)]
 pub struct SolverDatabase {
     map: HashMap<Day, Solver>,
+    generators: HashMap<Day, Box<dyn Generator>>,
+    variants: HashMap<Day, Vec<(&'static str, Solver)>>,
+    visualizers: HashMap<Day, Box<dyn Visualize>>,
+    plugins: Vec<Plugin>,
 }
 
-lazy_static! {
-    static ref DATABASE: SolverDatabase = {
+/// What the main solver for a day is called next to its variants.
+pub const MAIN_VARIANT: &str = "main";
+
+static DATABASE: OnceLock<SolverDatabase> = OnceLock::new();
+
+impl SolverDatabase {
+    /// The database of every solver, the built-in ones only unless [`SolverDatabase::init`] was
+    /// called first.
+    pub fn global() -> &'static Self {
+        DATABASE.get_or_init(Self::built_in)
+    }
+
+    /// Sets up the global database with the plugins in `plugin_dir` on top of the built-in
+    /// solvers, replacing the built-in solver of any day a plugin has.
+    ///
+    /// Fails if the database is already in use.
+    ///
+    /// # Safety
+    ///
+    /// The plugins are trusted, see [`Plugin::load`].
+    pub unsafe fn init(plugin_dir: &Path) -> anyhow::Result<&'static Self> {
+        let mut database = Self::built_in();
+        for plugin in plugin::load_dir(plugin_dir)? {
+            database.add_plugin(plugin);
+        }
+        DATABASE
+            .set(database)
+            .map_err(|_| anyhow::anyhow!("the solver database is already initialized"))?;
+        Ok(Self::global())
+    }
+
+    fn built_in() -> Self {
         let mut out = SolverDatabase::default();
         crate::solver::year_2021::add_all(&mut out);
         crate::solver::year_2024::add_all(&mut out);
         crate::solver::year_2025::add_all(&mut out);
 
         out
-    };
-}
+    }
 
-impl SolverDatabase {
-    pub fn global() -> &'static Self {
-        &DATABASE
+    pub fn add_plugin(&mut self, mut plugin: Plugin) {
+        for (day, solver) in plugin.take_solvers() {
+            self.add_solver(day, solver);
+        }
+        self.plugins.push(plugin);
+    }
+
+    pub fn plugins(&self) -> &[Plugin] {
+        &self.plugins
+    }
+
+    /// The plugin that solves `day`, if any.
+    pub fn get_plugin(&self, day: &Day) -> Option<&Plugin> {
+        // later plugins replace the days of earlier ones
+        self.plugins.iter().rev().find(|p| p.days.contains(day))
     }
 
     pub fn add_solver(&mut self, day: Day, solver: Solver) {
@@ -221,6 +829,164 @@ impl SolverDatabase {
     pub fn get_solver(&self, day: &Day) -> Option<&Solver> {
         self.map.get(day)
     }
+
+    /// Every day with a solver, in order.
+    pub fn days(&self) -> Vec<Day> {
+        let mut days = self.map.keys().copied().collect::<Vec<_>>();
+        days.sort_unstable();
+        days
+    }
+
+    /// Registers an alternative implementation for `day`, to pick with `--variant` or compare with `--diff`.
+    pub fn add_variant(&mut self, day: Day, name: &'static str, solver: Solver) {
+        self.variants.entry(day).or_default().push((name, solver));
+    }
+
+    /// The named variant, or the main solver for `None` or [`MAIN_VARIANT`].
+    pub fn get_variant(&self, day: &Day, name: Option<&str>) -> Option<&Solver> {
+        match name {
+            None | Some(MAIN_VARIANT) => self.get_solver(day),
+            Some(name) => self
+                .variants
+                .get(day)?
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, solver)| solver),
+        }
+    }
+
+    /// The main solver followed by every variant of `day`.
+    pub fn variants(&self, day: &Day) -> Vec<(&'static str, &Solver)> {
+        let main = self.get_solver(day).map(|solver| (MAIN_VARIANT, solver));
+        let variants = self.variants.get(day).into_iter().flatten();
+        main.into_iter()
+            .chain(variants.map(|(name, solver)| (*name, solver)))
+            .collect()
+    }
+
+    pub fn add_generator(&mut self, day: Day, generator: impl Generator) {
+        self.generators.insert(day, Box::new(generator));
+    }
+
+    pub fn get_generator(&self, day: &Day) -> Option<&dyn Generator> {
+        self.generators.get(day).map(Box::as_ref)
+    }
+
+    pub fn add_visualizer(&mut self, day: Day, visualizer: impl Visualize) {
+        self.visualizers.insert(day, Box::new(visualizer));
+    }
+
+    pub fn get_visualizer(&self, day: &Day) -> Option<&dyn Visualize> {
+        self.visualizers.get(day).map(Box::as_ref)
+    }
 }
 
 // pub struct
+
+#[test]
+fn should_detect_placeholder_parts() {
+    fn part(_: Box<dyn BufRead>) -> anyhow::Result<u8> {
+        Ok(0)
+    }
+
+    assert_eq!(
+        [true, true],
+        Solver::separated((part, part)).implemented_parts()
+    );
+    assert_eq!(
+        [true, false],
+        Solver::separated((part, ())).implemented_parts()
+    );
+    assert_eq!(
+        [false, true],
+        Solver::separated(((), part)).implemented_parts()
+    );
+}
+
+#[test]
+fn should_not_compare_unimplemented_parts() {
+    fn part(_: Box<dyn BufRead>) -> anyhow::Result<u8> {
+        Ok(4)
+    }
+    let solver = (part, ());
+
+    // when
+    let p_2 = solver.answer_part_2(Box::new(io::empty())).unwrap();
+    let answer = Answer::both(
+        solver.answer_part_1(Box::new(io::empty())).unwrap(),
+        p_2.clone(),
+    );
+
+    // then
+    assert_eq!(AnswerValue::Unimplemented, p_2);
+    assert_eq!("P1: 4, P2: not implemented", answer.to_string());
+    assert!(answer.passed(&Answer::p1("4")));
+    assert!(!answer.passed(&Answer::p1("5")));
+    assert_eq!(
+        Verdict::Unimplemented(2),
+        answer.check(&Answer::both("4", "6"))
+    );
+    assert_eq!(Verdict::Wrong, answer.check(&Answer::both("5", "6")));
+    assert_ne!(Some(&AnswerValue::from("not implemented")), answer.part_2());
+}
+
+#[test]
+fn should_resolve_meta_paths_relative_to_file() {
+    // given
+    let dir = std::env::temp_dir().join(format!("aoc-meta-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("meta.json");
+    std::fs::write(
+        &path,
+        r#"{"1-2024": {"full": "2024/full-1-24.txt", "example": {"Single": {"path": "2024/ex-1-24.txt", "expected_answer": {"P1": "11"}}}}}"#,
+    )
+    .unwrap();
+    // when
+    let meta = DaysMeta::load(&path).unwrap();
+    meta.save(&path).unwrap();
+    let saved = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    // then
+    let info = meta.get_day(Day::new(1, 2024)).unwrap();
+    assert_eq!(dir.join("2024/full-1-24.txt"), info.full);
+    assert!(saved.contains(r#""full": "2024/full-1-24.txt""#));
+}
+
+#[test]
+fn should_keep_plain_arrays_on_one_line() {
+    // given
+    let pretty = serde_json::to_string_pretty(&serde_json::json!({
+        "answer": {"Both": ["11", "31"]},
+        "rejected": [{"part": 1, "answer": "3"}],
+        "empty": [],
+    }))
+    .unwrap();
+    // when
+    let inlined = inline_arrays(&pretty);
+    // then
+    assert_eq!(
+        r#"{
+  "answer": {
+    "Both": ["11", "31"]
+  },
+  "empty": [],
+  "rejected": [
+    {
+      "answer": "3",
+      "part": 1
+    }
+  ]
+}"#,
+        inlined
+    );
+}
+
+#[test]
+fn should_reject_missing_expected_answers() {
+    // given
+    let example = r#"{"Single": {"path": "ex.txt", "expected_answer": {"Both": ["7", null]}}}"#;
+    // when
+    let read = serde_json::from_str::<Example>(example);
+    // then
+    assert!(read.is_err());
+}
@@ -0,0 +1,127 @@
+use crate::day::{Answer, AnswerValue};
+use colored::Colorize;
+use std::fmt::{Display, Formatter};
+
+/// Per-part comparison between an expected and an actual answer.
+pub struct AnswerDiff<'a> {
+    pub expected: &'a Answer,
+    pub actual: &'a Answer,
+}
+
+impl<'a> AnswerDiff<'a> {
+    pub fn new(expected: &'a Answer, actual: &'a Answer) -> Self {
+        Self { expected, actual }
+    }
+}
+
+impl Display for AnswerDiff<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let parts = [
+            (1, self.expected.part_1(), self.actual.part_1()),
+            (2, self.expected.part_2(), self.actual.part_2()),
+        ];
+
+        let mut first = true;
+        for (part, expected, actual) in parts {
+            // only compare the parts that have an expected answer
+            let Some(expected) = expected else { continue };
+            if !first {
+                writeln!(f)?;
+            }
+            first = false;
+
+            match actual {
+                Some(AnswerValue::Unimplemented) => write!(
+                    f,
+                    "  part {part} {} expected {} but it is not implemented",
+                    "-".yellow(),
+                    expected.to_string().green()
+                )?,
+                Some(actual) if actual == expected => {
+                    write!(f, "  part {part} {} {}", "✓".green(), actual.indented(6))?
+                }
+                Some(actual) if expected.is_multiline() || actual.is_multiline() => {
+                    write!(f, "  part {part} {}", "✗".red())?;
+                    write_lines(f, expected, actual)?;
+                }
+                Some(actual) => write!(
+                    f,
+                    "  part {part} {} expected {} but was {}",
+                    "✗".red(),
+                    expected.to_string().green(),
+                    actual.to_string().red()
+                )?,
+                None => write!(
+                    f,
+                    "  part {part} {} expected {} but was missing",
+                    "✗".red(),
+                    expected.to_string().green()
+                )?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn lines(value: &AnswerValue) -> Vec<String> {
+    match value {
+        AnswerValue::Grid(lines) => lines.clone(),
+        value => vec![value.to_string()],
+    }
+}
+
+/// Line-by-line diff, marking expected lines with `-` and actual lines with `+`.
+fn write_lines(
+    f: &mut Formatter<'_>,
+    expected: &AnswerValue,
+    actual: &AnswerValue,
+) -> std::fmt::Result {
+    let (expected, actual) = (lines(expected), lines(actual));
+
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => write!(f, "\n        {}", e)?,
+            (e, a) => {
+                if let Some(e) = e {
+                    write!(f, "\n      {} {}", "-".green(), e.green())?;
+                }
+                if let Some(a) = a {
+                    write!(f, "\n      {} {}", "+".red(), a.red())?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn should_mark_failed_part() {
+    colored::control::set_override(false);
+    // given
+    let expected = Answer::both("26", "61229");
+    let actual = Answer::both("26", "61228");
+    // when
+    let diff = AnswerDiff::new(&expected, &actual).to_string();
+    // then
+    assert_eq!(
+        "  part 1 ✓ 26\n  part 2 ✗ expected 61229 but was 61228",
+        diff
+    );
+}
+
+#[test]
+fn should_diff_grid_lines() {
+    colored::control::set_override(false);
+    // given
+    let expected = Answer::p2("###\n#.#\n###");
+    let actual = Answer::both("17", "###\n##.\n###");
+    // when
+    let diff = AnswerDiff::new(&expected, &actual).to_string();
+    // then
+    assert_eq!(
+        "  part 2 ✗\n        ###\n      - #.#\n      + ##.\n        ###",
+        diff
+    );
+}
@@ -0,0 +1,156 @@
+//! Solvers that are other programs, named in the meta, so that prototypes in other languages can
+//! be run and checked like the solvers written in Rust.
+
+use crate::context::{self, Input};
+use crate::day::{AnswerValue, CombinedSolver};
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, ErrorKind, Read, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::thread;
+
+/// A program that reads the input on stdin and prints the answer to each part on its own line,
+/// or, for answers spanning several lines, separated by blank lines. A program printing only one
+/// answer has no part 2 yet.
+///
+/// `AOC_INPUT` tells it which input it is given: `example`, `full` or `other`.
+///
+/// Stored in the meta as the program followed by its arguments, run from the meta's directory.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ExternalCommand {
+    pub argv: Vec<String>,
+    #[serde(skip)]
+    pub dir: PathBuf,
+}
+
+impl ExternalCommand {
+    pub fn new(argv: Vec<String>) -> Self {
+        Self {
+            argv,
+            dir: PathBuf::new(),
+        }
+    }
+
+    fn program(&self) -> &str {
+        self.argv.first().map_or("", String::as_str)
+    }
+
+    /// Runs the command on `input`, telling it which `kind` of input that is, and returns what
+    /// it printed.
+    pub fn run(&self, kind: Input, mut input: Box<dyn BufRead>) -> anyhow::Result<String> {
+        let Some((program, args)) = self.argv.split_first() else {
+            bail!("empty command");
+        };
+        let mut bytes = Vec::new();
+        input.read_to_end(&mut bytes)?;
+
+        let mut command = Command::new(program);
+        if !self.dir.as_os_str().is_empty() {
+            command.current_dir(&self.dir);
+        }
+        let mut child = command
+            .args(args)
+            .env("AOC_INPUT", kind.to_string())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("couldn't start {program}"))?;
+
+        // feed the input from another thread, so that a large output can't block the command
+        let mut stdin = child.stdin.take().expect("stdin is piped");
+        let writer = thread::spawn(move || match stdin.write_all(&bytes) {
+            // the command doesn't have to read all of its input
+            Err(e) if e.kind() == ErrorKind::BrokenPipe => Ok(()),
+            r => r,
+        });
+        let output = child.wait_with_output()?;
+        writer.join().expect("writing the input shouldn't panic")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!(
+                "{program} failed with {}:\n{}",
+                output.status,
+                stderr.trim_end()
+            );
+        }
+        Ok(String::from_utf8(output.stdout)?)
+    }
+}
+
+impl CombinedSolver for ExternalCommand {
+    fn solve(&self, input: Box<dyn BufRead>) -> anyhow::Result<(String, String)> {
+        let (p_1, p_2) = self.answer(input)?;
+        Ok((p_1.to_string(), p_2.to_string()))
+    }
+
+    fn answer(&self, input: Box<dyn BufRead>) -> anyhow::Result<(AnswerValue, AnswerValue)> {
+        let output = self.run(context::Context::current().input(), input)?;
+        match &answers(&output)[..] {
+            [p_1] => Ok((p_1.as_str().into(), AnswerValue::Unimplemented)),
+            [p_1, p_2] => Ok((p_1.as_str().into(), p_2.as_str().into())),
+            answers => bail!(
+                "{} should print one answer per part, got {}",
+                self.program(),
+                answers.len()
+            ),
+        }
+    }
+}
+
+/// The answers in a command's output: its lines, or its blocks of lines if any line is blank.
+fn answers(output: &str) -> Vec<String> {
+    let lines = output.lines().map(str::trim_end).collect::<Vec<_>>();
+    if !lines.iter().any(|l| l.is_empty()) {
+        return lines.into_iter().map(String::from).collect();
+    }
+    lines
+        .split(|l| l.is_empty())
+        .filter(|block| !block.is_empty())
+        .map(|block| block.join("\n"))
+        .collect()
+}
+
+#[test]
+fn should_solve_with_command() {
+    // given
+    let command = ExternalCommand::new(
+        ["sh", "-c", "head -n 1; echo done"]
+            .map(String::from)
+            .to_vec(),
+    );
+    let failing = ExternalCommand::new(
+        ["sh", "-c", "echo oops >&2; exit 3"]
+            .map(String::from)
+            .to_vec(),
+    );
+    // when
+    let answer = command.solve(Box::new("a\nb\nc\n".as_bytes()));
+    let error = failing.solve(Box::new("".as_bytes())).unwrap_err();
+    // then
+    assert_eq!(("a".to_string(), "done".to_string()), answer.unwrap());
+    assert!(error.to_string().contains("oops"));
+}
+
+#[test]
+fn should_read_grids_and_single_answers() {
+    use std::io;
+
+    // given
+    let command =
+        |script: &str| ExternalCommand::new(["sh", "-c", script].map(String::from).to_vec());
+    let grid = command("echo 7; echo; echo '#.#'; echo '###'");
+    let single = command("echo 7");
+    let input = command("echo $AOC_INPUT");
+    // when
+    let grid = grid.answer(Box::new(io::empty())).unwrap();
+    let single = single.answer(Box::new(io::empty())).unwrap();
+    let input = input.run(Input::Example, Box::new(io::empty())).unwrap();
+    // then
+    assert_eq!((AnswerValue::Int(7), "#.#\n###".into()), grid);
+    assert_eq!((AnswerValue::Int(7), AnswerValue::Unimplemented), single);
+    assert_eq!("example\n", input);
+}
@@ -0,0 +1,138 @@
+//! Examples taken from a saved puzzle page, so that they don't have to be copied by hand.
+
+use crate::day::{Answer, Day, DayInfo, DaysMeta, Example};
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+static ARTICLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap());
+static BLOCK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap());
+static EMPHASIZED: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").unwrap()
+});
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+
+/// What a puzzle page offers for the example.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct PuzzlePage {
+    /// Every `<pre><code>` block, in order
+    pub blocks: Vec<String>,
+    /// The emphasized code of each part that is on the page, in order
+    pub emphasized: Vec<Vec<String>>,
+}
+
+impl PuzzlePage {
+    pub fn parse(html: &str) -> Self {
+        let text = |html: &str| decode(&TAG.replace_all(html, ""));
+        let blocks = BLOCK.captures_iter(html).map(|c| text(&c[1])).collect();
+
+        // each part is its own article once part 1 is solved; older saves may have none
+        let mut parts = ARTICLE
+            .captures_iter(html)
+            .map(|c| c.get(1).unwrap().as_str())
+            .collect::<Vec<_>>();
+        if parts.is_empty() {
+            parts.push(html);
+        }
+        let emphasized = parts
+            .iter()
+            .map(|part| {
+                EMPHASIZED
+                    .captures_iter(part)
+                    .map(|c| text(c.get(1).or(c.get(2)).unwrap().as_str()))
+                    .collect()
+            })
+            .collect();
+
+        Self { blocks, emphasized }
+    }
+
+    /// The last emphasized code of each part, which is the example's answer in most puzzles.
+    pub fn expected_answer(&self) -> Option<Answer> {
+        let last = |part: usize| self.emphasized.get(part)?.last().cloned();
+        Answer::from_parts(last(0).map(Into::into), last(1).map(Into::into))
+    }
+}
+
+/// Where the example of `day` goes, relative to the data directory.
+pub fn example_path(day: Day) -> PathBuf {
+    PathBuf::from(format!(
+        "{}/ex-{}-{}.txt",
+        day.year,
+        day.day,
+        day.year % 100
+    ))
+}
+
+/// Where the full input of `day` goes, relative to the data directory.
+pub fn full_path(day: Day) -> PathBuf {
+    PathBuf::from(format!(
+        "{}/full-{}-{}.txt",
+        day.year,
+        day.day,
+        day.year % 100
+    ))
+}
+
+/// Sets the example of `day` in the meta to the file at `path`, adding the day if it is new.
+pub fn set_example(meta: &mut DaysMeta, day: Day, data_dir: &Path, path: PathBuf, answer: Answer) {
+    let example = Example::Single {
+        path,
+        expected_answer: answer,
+    };
+    match meta.get_day_mut(day) {
+        Some(info) => info.example = example,
+        None => {
+            meta.0.insert(
+                day.to_string(),
+                DayInfo {
+                    full: data_dir.join(full_path(day)),
+                    example,
+                    verified: None,
+                    rejected: vec![],
+                    command: None,
+                    budget_ms: None,
+                },
+            );
+        }
+    }
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[test]
+fn should_extract_blocks_and_answers() {
+    // given
+    let html = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 8: Playground ---</h2>
+<p>For example:</p>
+<pre><code>162,817,812
+57,618,57
+</code></pre>
+<p>Then <code>a &lt; b</code> and the product is <code><em>40</em></code>.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code><em>X</em> &amp; Y
+</code></pre>
+<p>Multiplying gives <em><code>25272</code></em>.</p>
+</article>
+</main></body></html>"#;
+    // when
+    let page = PuzzlePage::parse(html);
+    // then
+    assert_eq!(vec!["162,817,812\n57,618,57\n", "X & Y\n"], page.blocks);
+    assert_eq!(Some(Answer::both("40", "25272")), page.expected_answer());
+    assert_eq!(
+        PathBuf::from("2025/ex-8-25.txt"),
+        example_path(Day::new(8, 2025))
+    );
+}
@@ -0,0 +1,212 @@
+//! Runs days in child processes of the same binary with memory and CPU time limits, so that a
+//! runaway search fails on its own instead of taking the machine down.
+
+use crate::day::{Day, DayInfo};
+use crate::profile::SpanReport;
+use crate::runner::{DayResult, DayRun};
+use anyhow::Context;
+use std::env;
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus, Stdio};
+use std::time::Duration;
+use thiserror::Error;
+
+/// The hidden flag that makes the binary run a single day for [`Isolation::run`].
+pub const CHILD_FLAG: &str = "--isolated-day";
+
+const MIB: u64 = 1024 * 1024;
+
+/// How to run days in child processes.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Isolation {
+    /// Cap on the address space of the child, in bytes
+    pub memory: u64,
+    /// Cap on the CPU time of the child, over all of its threads
+    pub cpu: Duration,
+    /// Passed on to the child, which otherwise finds its config like the parent
+    pub data_dir: Option<PathBuf>,
+}
+
+/// Why an isolated day didn't produce a result.
+#[derive(Error, Debug)]
+pub enum Failure {
+    #[error("out of memory (limit {} MiB)", .0 / MIB)]
+    OutOfMemory(u64),
+    #[error("segfault, possibly out of memory (limit {} MiB)", .0 / MIB)]
+    Segfault(u64),
+    #[error("timed out after {0:.0?} of CPU time")]
+    TimedOut(Duration),
+    #[error("killed by signal {0}{}", signal_name(*.0).map(|n| format!(" ({n})")).unwrap_or_default())]
+    Killed(i32),
+    #[error("failed: {0}")]
+    Failed(String),
+}
+
+#[derive(Error, Debug)]
+#[error("{day}: {failure}")]
+pub struct IsolatedFailure {
+    pub day: Day,
+    pub failure: Failure,
+}
+
+impl Isolation {
+    pub fn new(memory_mib: u64, cpu: Duration) -> Self {
+        Self {
+            memory: memory_mib * MIB,
+            cpu,
+            data_dir: None,
+        }
+    }
+
+    /// Runs both parts of `day` on its full input in a child process.
+    ///
+    /// Hitting a limit or crashing gives an [`IsolatedFailure`].
+    pub fn run(&self, day: Day, info: &DayInfo) -> anyhow::Result<DayRun> {
+        let mut command = Command::new(env::current_exe()?);
+        if let Some(dir) = &self.data_dir {
+            command.arg("--data-dir").arg(dir);
+        }
+        command
+            .arg(CHILD_FLAG)
+            .arg(day.to_string())
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        self.limit(&mut command);
+
+        let output = command
+            .output()
+            .context("couldn't start the child process")?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let failure = self.failure(output.status, &stderr);
+            return Err(IsolatedFailure { day, failure }.into());
+        }
+
+        let result: DayResult = serde_json::from_slice(&output.stdout)
+            .context("couldn't read the result of the child process")?;
+        Ok(DayRun {
+            day,
+            warnings: info.check_answer(&result.answer()),
+            result,
+            spans: SpanReport::default(),
+            cached: false,
+        })
+    }
+
+    #[cfg(unix)]
+    fn limit(&self, command: &mut Command) {
+        use std::os::unix::process::CommandExt;
+
+        let memory = self.memory;
+        let cpu = self.cpu.as_secs().max(1);
+        // SAFETY: only calls setrlimit, which is async-signal-safe
+        unsafe {
+            command.pre_exec(move || {
+                let set = |resource, soft: u64, hard: u64| {
+                    let limit = libc::rlimit {
+                        rlim_cur: soft as libc::rlim_t,
+                        rlim_max: hard as libc::rlim_t,
+                    };
+                    match libc::setrlimit(resource, &limit) {
+                        0 => Ok(()),
+                        _ => Err(std::io::Error::last_os_error()),
+                    }
+                };
+                set(libc::RLIMIT_AS, memory, memory)?;
+                // SIGXCPU at the soft limit, which ends the process; SIGKILL a second later
+                set(libc::RLIMIT_CPU, cpu, cpu + 1)
+            });
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn limit(&self, _command: &mut Command) {}
+
+    fn failure(&self, status: ExitStatus, stderr: &str) -> Failure {
+        // the default allocation error handler prints this before aborting
+        if stderr.contains("memory allocation of") {
+            return Failure::OutOfMemory(self.memory);
+        }
+        match signal(status) {
+            Some(CPU_SIGNAL) => Failure::TimedOut(self.cpu),
+            // the address space cap also fails mapping thread stacks and libraries, which
+            // segfaults instead of going through the allocation error handler
+            Some(SEGV_SIGNAL) => Failure::Segfault(self.memory),
+            Some(signal) => Failure::Killed(signal),
+            None => Failure::Failed(error_message(stderr).unwrap_or("no output").to_string()),
+        }
+    }
+}
+
+/// The signal a process gets for going over its CPU time limit.
+#[cfg(unix)]
+const CPU_SIGNAL: i32 = libc::SIGXCPU;
+#[cfg(not(unix))]
+const CPU_SIGNAL: i32 = -1;
+
+/// The signal a process gets for an invalid memory access.
+#[cfg(unix)]
+const SEGV_SIGNAL: i32 = libc::SIGSEGV;
+#[cfg(not(unix))]
+const SEGV_SIGNAL: i32 = -2;
+
+/// Names of the signals a crashing or killed solver usually gets.
+fn signal_name(signal: i32) -> Option<&'static str> {
+    match signal {
+        6 => Some("SIGABRT"),
+        9 => Some("SIGKILL"),
+        15 => Some("SIGTERM"),
+        _ => None,
+    }
+}
+
+#[cfg(unix)]
+fn signal(status: ExitStatus) -> Option<i32> {
+    use std::os::unix::process::ExitStatusExt;
+    status.signal()
+}
+
+#[cfg(not(unix))]
+fn signal(_status: ExitStatus) -> Option<i32> {
+    None
+}
+
+/// The error the child printed, which anyhow starts with `Error: `, or else its last line.
+fn error_message(stderr: &str) -> Option<&str> {
+    let mut lines = stderr.lines().map(str::trim).filter(|l| !l.is_empty());
+    lines
+        .clone()
+        .find_map(|l| l.strip_prefix("Error: "))
+        .or_else(|| lines.next_back())
+}
+
+#[cfg(unix)]
+#[test]
+fn should_classify_failures() {
+    use std::os::unix::process::ExitStatusExt;
+
+    // given
+    let isolation = Isolation::new(64, Duration::from_secs(2));
+    let oom = "memory allocation of 1073741824 bytes failed\n";
+    let error = "Error: No solver for 3-2030\n\nStack backtrace:\n   0: main\n";
+    // when
+    let failures = [
+        isolation.failure(ExitStatus::from_raw(libc::SIGABRT), oom),
+        isolation.failure(ExitStatus::from_raw(libc::SIGXCPU), ""),
+        isolation.failure(ExitStatus::from_raw(libc::SIGKILL), ""),
+        isolation.failure(ExitStatus::from_raw(libc::SIGSEGV), ""),
+        isolation.failure(ExitStatus::from_raw(1 << 8), error),
+    ];
+    // then
+    assert_eq!(
+        [
+            "out of memory (limit 64 MiB)",
+            "timed out after 2s of CPU time",
+            "killed by signal 9 (SIGKILL)",
+            "segfault, possibly out of memory (limit 64 MiB)",
+            "failed: No solver for 3-2030",
+        ],
+        failures.map(|f| f.to_string())
+    );
+}
@@ -0,0 +1,19 @@
+pub mod alloc;
+pub mod cache;
+pub mod cli;
+pub mod config;
+pub mod context;
+pub mod crypt;
+pub mod day;
+pub mod diff;
+pub mod external;
+pub mod import;
+pub mod isolate;
+pub mod plugin;
+pub mod profile;
+pub mod runner;
+pub mod server;
+pub mod solver;
+pub mod status;
+pub mod submit;
+pub mod visualize;
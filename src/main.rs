@@ -1,435 +1,719 @@
-use std::fmt::{Display, Formatter};
-use std::fs::File;
+use std::fs;
 use std::io;
-use std::io::BufReader;
-use std::path::Path;
-use std::time::{Duration, Instant};
+use std::io::{IsTerminal, Write};
+use std::sync::Arc;
+use std::time::Duration;
+
+use colored::Colorize;
+use serde_json::{json, Value};
+
+use advent_of_code::cache::{ResultCache, CACHE_FILE};
+use advent_of_code::cli::{
+    Cli, CryptArgs, GenArgs, ImportArgs, PartArgs, ProfileArgs, RunArgs, RunType, SubmitArgs,
+    VisualizeArgs,
+};
+use advent_of_code::config::{Config, OutputFormat};
+use advent_of_code::context::Context;
+use advent_of_code::crypt::{self, Key};
+use advent_of_code::day::{Answer, Day, DayInfo, DaysMeta, Hint, SolverDatabase};
+use advent_of_code::import::{self, PuzzlePage};
+use advent_of_code::isolate::{IsolatedFailure, Isolation};
+use advent_of_code::runner::{
+    alloc_suffix, time_fn, DayResult, RunError, RunResult, RunSingleResult,
+};
+use advent_of_code::server::{answer_json, Server};
+use advent_of_code::submit::{SubmitClient, SubmitOutcome};
+use advent_of_code::{profile, runner, status, visualize};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: advent_of_code::alloc::CountingAllocator = advent_of_code::alloc::CountingAllocator;
 
-use itertools::Itertools;
-use thiserror::Error;
-
-use crate::cli::{ExampleReader, ReadersError, RunArgs, RunType, SourceReader};
-use crate::day::{Answer, Day, DayInfo, DaysMeta, Solver, SolverDatabase};
+fn main() -> anyhow::Result<()> {
+    if !io::stdout().is_terminal() {
+        colored::control::set_override(false);
+    }
 
-pub mod cli;
-pub mod day;
-pub mod solver;
+    let mut config = Config::load()?;
+    let Cli {
+        run: args,
+        log,
+        data_dir,
+        format,
+        no_cache,
+        isolate,
+        runs,
+    } = Cli::parse(config.year);
+    config.data_dir = data_dir.or(config.data_dir);
+    config.format = format.unwrap_or(config.format);
+
+    crypt::set_key_file(config.key_file());
+
+    // SAFETY: plugins are libraries put in the plugins directory on purpose
+    unsafe { SolverDatabase::init(&config.plugin_dir())? };
+
+    let meta_path = config.meta_path();
+    let meta = match DaysMeta::load(&meta_path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => DaysMeta::default(),
+        meta => meta?,
+    };
 
-fn main() -> anyhow::Result<()> {
-    let args = RunType::parse();
-    let meta = parse_meta(Path::new("data/meta.json")).unwrap_or_default();
+    // progress lines only make sense when a single day is being watched
+    let single = matches!(args, RunType::Args(_) | RunType::Submit(_));
+    Context::new()
+        .with_progress(single && config.format == OutputFormat::Text && io::stderr().is_terminal())
+        .with_logging(log.verbosity, log.days)
+        .install();
 
     match args {
         RunType::Interactive => interactive(meta),
         RunType::All => {
-            run_all(&meta)?;
+            let cache_path = config.data_dir().join(CACHE_FILE);
+            let mut cache = (config.cache && !no_cache).then(|| ResultCache::load(&cache_path));
+            let isolation = isolate.map(|isolation| Isolation {
+                data_dir: config.data_dir.clone(),
+                ..isolation
+            });
+            let budget = config.default_budget();
+            let (failed, over) = if config.format == OutputFormat::Json {
+                print_all_json(&meta, cache.as_mut(), isolation.as_ref(), runs, budget)?
+            } else {
+                print_all(&meta, cache.as_mut(), isolation.as_ref(), runs, budget)?
+            };
+            if let Some(cache) = cache {
+                cache.save(&cache_path)?;
+            }
+            let problems = [(failed, "failed"), (over, "over budget")]
+                .iter()
+                .filter(|(count, _)| *count > 0)
+                .map(|(count, what)| format!("{count} days {what}"))
+                .collect::<Vec<_>>();
+            if !problems.is_empty() {
+                anyhow::bail!("{}", problems.join(", "));
+            }
         }
         RunType::Args(args) => {
-            let result = run_from_args(args, meta);
+            let day = args.day;
+            let full = args.source.uses_full();
+            let result = runner::run_day(day, args.variant.as_deref(), args.source, &meta);
+            if config.format == OutputFormat::Json {
+                let info = meta.get_day(day).filter(|_| full);
+                println!("{}", run_json(day, &result, info));
+                return Ok(());
+            }
             match result {
-                Ok(r) => println!("{}", r),
+                Ok(r) => {
+                    println!("{}", r);
+                    print_spans("Spans:");
+                    if let (true, Some(info), Some(answer)) = (full, meta.get_day(day), r.answer())
+                    {
+                        print_warnings(info, &answer);
+                    }
+                }
                 Err(e) => println!("Err: {}", e),
             }
         }
+        RunType::Submit(args) => submit(args, meta, &config)?,
+        RunType::Profile(args) => run_profile(args, &meta, config.default_budget())?,
+        RunType::Gen(args) => generate(args)?,
+        RunType::Diff(args) => diff(args, &meta)?,
+        RunType::List(year) => list(&meta, year),
+        RunType::Calendar(year) => calendar(&meta, year),
+        RunType::Encrypt(args) => crypt_inputs(args, &meta, true)?,
+        RunType::Decrypt(args) => crypt_inputs(args, &meta, false)?,
+        RunType::Visualize(args) => visualize(args, &meta)?,
+        RunType::Serve => Server::new(&meta)
+            .with_budget(config.default_budget())
+            .serve(io::stdin().lock(), io::stdout().lock())?,
+        RunType::ImportExamples(args) => import_examples(args, meta, &config)?,
+        RunType::Isolated(day) => {
+            let info = meta.get_day(day).ok_or(RunError::NoMeta(day))?;
+            let run = runner::run_full(day, info)?;
+            println!("{}", serde_json::to_string(&run.result)?);
+        }
     }
 
     Ok(())
 }
 
-fn parse_meta(path: &Path) -> Option<DaysMeta> {
-    let file = File::open(path).ok()?;
-    let reader = BufReader::new(file);
-    // should panic if unable to read meta
-    Some(serde_json::from_reader(reader).unwrap())
+fn print_warnings(info: &DayInfo, answer: &Answer) {
+    for warning in info.check_answer(answer) {
+        println!("Warning: {}", warning);
+    }
+}
+
+fn print_spans(header: &str) {
+    let spans = profile::take_spans();
+    if !spans.is_empty() {
+        println!("{header}{spans}");
+    }
 }
 
 fn interactive(_meta: DaysMeta) {
     println!("Ran interactive");
 }
 
-#[derive(Error, Debug)]
-#[error("No solver for {0}")]
-pub struct NoSolver(Day);
-
-fn run_all(meta: &DaysMeta) -> anyhow::Result<Duration> {
+/// Prints every day as it is done, returning how many of them failed in a child process and how
+/// many went over their budget.
+fn print_all(
+    meta: &DaysMeta,
+    cache: Option<&mut ResultCache>,
+    isolation: Option<&Isolation>,
+    runs: usize,
+    budget: Option<Duration>,
+) -> anyhow::Result<(usize, usize)> {
     let mut total = Duration::default();
-
-    let mut days: Vec<(Day, &DayInfo)> = meta
-        .0
-        .iter()
-        .map(|(s, i)| {
-            let day = s.parse().unwrap();
-            (day, i)
-        })
-        .collect_vec();
-
-    days.sort_unstable_by_key(|x| x.0);
-
-    for (day, info) in days {
-        let full = File::open(&info.full)?;
-        let full = Box::new(BufReader::new(full));
-
-        let solver = SolverDatabase::global()
-            .get_solver(&day)
-            .ok_or(NoSolver(day))?;
-        match solver {
-            Solver::Combined(solver) => {
-                let (r, t) = time_fn(|| solver.solve(full));
-                let (p_1, p_2) = r?;
-                println!("{day}: {t:.2?}");
-                println!("    {}", p_1);
-                println!("    {}", p_2);
-
-                total += t;
+    let mut cached = 0;
+    let mut failed = 0;
+    let mut over = Vec::new();
+
+    for run in runner::run_all(meta, cache, isolation, runs) {
+        // days that fail in a child process don't stop the others
+        let run = match run {
+            Err(e) if e.is::<IsolatedFailure>() => {
+                println!("{}", e.to_string().red());
+                failed += 1;
+                continue;
             }
-            Solver::Separated(solver) => {
-                let (r_1, t_1) = time_fn(|| solver.part_1(full));
-                let a_1 = r_1?;
-
-                let full = File::open(&info.full)?;
-                let full = Box::new(BufReader::new(full));
-                let (r_2, t_2) = time_fn(|| solver.part_2(full));
+            run => run?,
+        };
+        // cached times are from the run that was cached, so they are shown but not counted
+        let marker = if run.cached {
+            " (cached)".dimmed().to_string()
+        } else {
+            String::new()
+        };
+        match &run.result {
+            DayResult::Combined {
+                answer: (p_1, p_2),
+                time,
+                alloc,
+            } => {
+                println!("{}: {time:.2?}{}{marker}", run.day, alloc_suffix(*alloc));
+                println!("    {}", p_1.indented(4));
+                println!("    {}", p_2.indented(4));
+            }
+            DayResult::Separated(parts) => {
+                println!("{}: {:.2?}{marker}", run.day, run.result.time());
+                for part in parts {
+                    println!(
+                        "    {} in {:.2?}{}",
+                        part.answer.indented(4),
+                        part.time,
+                        alloc_suffix(part.alloc)
+                    );
+                }
+            }
+        }
+        for warning in &run.warnings {
+            println!("Warning: {}", warning);
+        }
+        if !run.spans.is_empty() {
+            println!("  spans:{}", run.spans);
+        }
 
-                let a_2 = r_2?;
+        if let Some(budget) = run.over_budget(day_budget(meta, run.day, budget)) {
+            over.push((run.day, run.result.time(), budget));
+        }
 
-                println!("{day}: {:.2?}", t_1 + t_2);
-                println!("    {} in {:.2?}", a_1, t_1);
-                println!("    {} in {:.2?}", a_2, t_2);
+        if run.cached {
+            cached += 1;
+        } else {
+            total += run.result.time();
+        }
+    }
 
-                total += t_1;
-                total += t_2;
-            }
+    if !over.is_empty() {
+        println!("{}", "Over budget:".red());
+        for (day, time, budget) in &over {
+            println!("    {day}: {time:.2?} > {budget:.2?}");
         }
     }
 
-    println!("Finished all in {:.2?}", total);
+    let notes = [
+        (cached, "cached"),
+        (failed, "failed"),
+        (over.len(), "over budget"),
+    ]
+    .iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, what)| format!("{count} days {what}"))
+    .collect::<Vec<_>>();
+    if notes.is_empty() {
+        println!("Finished all in {:.2?}", total);
+    } else {
+        println!("Finished all in {:.2?} ({})", total, notes.join(", "));
+    }
+
+    Ok((failed, over.len()))
+}
+
+/// One JSON object per day, written as soon as the day is done. Returns how many days failed in a
+/// child process and how many went over their budget.
+fn print_all_json(
+    meta: &DaysMeta,
+    cache: Option<&mut ResultCache>,
+    isolation: Option<&Isolation>,
+    runs: usize,
+    budget: Option<Duration>,
+) -> anyhow::Result<(usize, usize)> {
+    let mut failed = 0;
+    let mut over = 0;
+    for run in runner::run_all(meta, cache, isolation, runs) {
+        let run = match run {
+            Err(e) => match e.downcast::<IsolatedFailure>() {
+                Ok(IsolatedFailure { day, failure }) => {
+                    let error = failure.to_string();
+                    println!("{}", json!({ "day": day.to_string(), "error": error }));
+                    failed += 1;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            },
+            Ok(run) => run,
+        };
+        let budget = day_budget(meta, run.day, budget);
+        let over_budget = run.over_budget(budget).is_some();
+        over += usize::from(over_budget);
+        println!(
+            "{}",
+            json!({
+                "day": run.day.to_string(),
+                "answer": answer_json(&run.result.answer()),
+                "time_ms": millis(run.result.time()),
+                "cached": run.cached,
+                "budget_ms": budget.map(millis),
+                // cached days aren't checked
+                "over_budget": (!run.cached).then_some(over_budget),
+                "warnings": run.warnings.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            })
+        );
+    }
+    profile::take_spans();
 
-    Ok(total)
+    Ok((failed, over))
 }
 
-#[derive(Error, Debug)]
-pub enum RunError {
-    #[error(transparent)]
-    Io(#[from] io::Error),
-    #[error("no solver for {0}")]
-    NoSolver(Day),
-    #[error("no meta for {0}")]
-    NoMeta(Day),
-    #[error(
-        "wrong answer for example in part {part}: expected \"{expected}\" but was \"{actual}\""
-    )]
-    ExampleWrongAnswer {
-        part: Part,
-        expected: Answer,
-        actual: Answer,
-    },
-    #[error("Used a combined solver for a separated example")]
-    CombinedForSeparatedAnswer,
+/// The budget of `day` from the meta, or else `default`.
+fn day_budget(meta: &DaysMeta, day: Day, default: Option<Duration>) -> Option<Duration> {
+    match meta.get_day(day) {
+        Some(info) => info.budget(default),
+        None => default,
+    }
 }
 
-#[derive(Debug)]
-pub enum Part {
-    P1,
-    P2,
-    Both,
+fn run_json(day: Day, result: &Result<RunResult, RunError>, info: Option<&DayInfo>) -> Value {
+    let result = match result {
+        Ok(result) => result,
+        Err(e) => return json!({ "day": day.to_string(), "error": e.to_string() }),
+    };
+    let part = |r: &RunSingleResult| match &r.result {
+        Ok(answer) => json!({ "answer": answer_json(answer), "time_ms": millis(r.time) }),
+        Err(e) => json!({ "error": e.to_string() }),
+    };
+    let parts = match result {
+        RunResult::Single(r) => vec![part(r)],
+        RunResult::Multi(r_1, r_2) => vec![part(r_1), part(r_2)],
+    };
+    let warnings = match (info, result.answer()) {
+        (Some(info), Some(answer)) => info.check_answer(&answer),
+        _ => vec![],
+    };
+    profile::take_spans();
+
+    json!({
+        "day": day.to_string(),
+        "results": parts,
+        "warnings": warnings.iter().map(ToString::to_string).collect::<Vec<_>>(),
+    })
 }
 
-impl Display for Part {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Part::P1 => "1",
-                Part::P2 => "2",
-                Part::Both => "1&2",
-            }
-        )
+fn millis(time: Duration) -> f64 {
+    time.as_secs_f64() * 1000.0
+}
+
+fn submit(args: SubmitArgs, mut meta: DaysMeta, config: &Config) -> anyhow::Result<()> {
+    let SubmitArgs { day, part } = args;
+    let client = SubmitClient::from_env(config.session_file.as_deref())?;
+
+    let (answer, time, _) = time_fn(|| runner::solve_part(day, &part, &meta));
+    let answer = answer?;
+    let level = if part == PartArgs::P1 { 1 } else { 2 };
+    println!("{day} part {level}: {answer} in {time:.2?}");
+    if !answer.is_implemented() {
+        println!("Not submitting: part {level} is not implemented");
+        return Ok(());
     }
+
+    let info = meta.get_day_mut(day).ok_or(RunError::NoMeta(day))?;
+    if let Some(warning) = info.check(level, &answer) {
+        println!("Not submitting: {warning}");
+        return Ok(());
+    }
+
+    let outcome = client.submit(day, level, &answer.to_string())?;
+    println!("Submitted: {outcome}");
+
+    match outcome {
+        SubmitOutcome::Correct => {
+            let verified = info.verified.take();
+            let (p_1, p_2) = match verified {
+                Some(v) => (v.part_1().cloned(), v.part_2().cloned()),
+                None => (None, None),
+            };
+            info.verified = if level == 1 {
+                Answer::from_parts(Some(answer), p_2)
+            } else {
+                Answer::from_parts(p_1, Some(answer))
+            };
+        }
+        SubmitOutcome::Wrong => info.reject(level, answer, None),
+        SubmitOutcome::TooHigh => info.reject(level, answer, Some(Hint::TooHigh)),
+        SubmitOutcome::TooLow => info.reject(level, answer, Some(Hint::TooLow)),
+        _ => return Ok(()),
+    }
+    meta.save(&config.meta_path())?;
+
+    Ok(())
 }
 
-// Situations needed to handle:
-// Run only part 1 (example and full)
-// Run only part 2 (example and full)
-// Run both part 1 and 2
-fn run_from_args(args: RunArgs, meta: DaysMeta) -> Result<RunResult, RunError> {
-    let RunArgs {
+fn generate(args: GenArgs) -> anyhow::Result<()> {
+    let GenArgs {
         day,
-        source,
-        part: _,
+        seed,
+        size,
+        output,
     } = args;
+    let input = runner::generate(day, seed, size)?;
+    fs::write(&output, input)?;
+    println!(
+        "Wrote {}, run it with -d {} -y {} -f {}",
+        output.display(),
+        day.day,
+        day.year,
+        output.display()
+    );
 
-    let Some(solver) = SolverDatabase::global().get_solver(&day) else {
-        return Err(RunError::NoSolver(day));
-    };
+    Ok(())
+}
 
-    let reader = source.clone().to_readers(&meta, day).map_err(|e| match e {
-        ReadersError::NoMeta(day) => RunError::NoMeta(day),
-        ReadersError::Io(io) => io.into(),
-    })?;
-
-    match reader {
-        SourceReader::Simple(reader) => match solver {
-            Solver::Combined(solver) => {
-                let (result, time) = time_fn(|| {
-                    solver
-                        .solve(reader)
-                        .map(|(s_1, s_2)| Answer::Both(s_1, s_2))
-                });
-                Ok(RunResult::Single(RunSingleResult { result, time }))
+fn import_examples(args: ImportArgs, mut meta: DaysMeta, config: &Config) -> anyhow::Result<()> {
+    let ImportArgs { day, page, block } = args;
+    let html = fs::read_to_string(&page)?;
+    let page = PuzzlePage::parse(&html);
+    if page.blocks.is_empty() {
+        anyhow::bail!("the page has no code blocks");
+    }
+
+    // without --block the user picks the block and can correct the answers
+    let interactive = block.is_none();
+    let index = match block {
+        Some(n) => n - 1,
+        None => {
+            for (i, block) in page.blocks.iter().enumerate() {
+                println!("{}:", format!("Block {}", i + 1).bold());
+                for line in block.lines().take(6) {
+                    println!("    {line}");
+                }
+                if block.lines().count() > 6 {
+                    println!("    {}", "...".dimmed());
+                }
             }
-            Solver::Separated(solver) => {
-                let SourceReader::Simple(reader_2) = source.to_readers(&meta, day).unwrap() else {
-                    unreachable!()
-                };
-
-                let (result_1, time_1) = time_fn(|| solver.part_1(reader));
-
-                Ok(match result_1 {
-                    Ok(part_1) => {
-                        let (result_2, time_2) =
-                            time_fn(|| solver.part_2(reader_2).map(Answer::P2));
-
-                        RunResult::Multi(
-                            RunSingleResult {
-                                result: Ok(Answer::P1(part_1)),
-                                time: time_1,
-                            },
-                            RunSingleResult {
-                                result: result_2,
-                                time: time_2,
-                            },
-                        )
-                    }
-                    Err(e) => RunResult::Single(RunSingleResult {
-                        result: Err(e),
-                        time: time_1,
-                    }),
-                })
+            prompt("Example block", "1")?
+                .parse::<usize>()?
+                .saturating_sub(1)
+        }
+    };
+    let example = page
+        .blocks
+        .get(index)
+        .ok_or_else(|| anyhow::anyhow!("the page has {} code blocks", page.blocks.len()))?;
+
+    let answer = if interactive {
+        let mut parts = [None, None];
+        for (part, emphasized) in page.emphasized.iter().take(2).enumerate() {
+            if let Some(last) = emphasized.last() {
+                println!("Emphasized in part {}: {}", part + 1, emphasized.join(", "));
+                parts[part] = Some(prompt(&format!("Part {} answer", part + 1), last)?.into());
             }
-        },
-        SourceReader::Example(example, full) => {
-            match (solver, example) {
-                (
-                    Solver::Combined(solver),
-                    ExampleReader::Single {
-                        file,
-                        expected_answer,
-                    },
-                ) => {
-                    let (result, time) =
-                        time_fn(|| solver.solve(file).map(|(s_1, s_2)| Answer::Both(s_1, s_2)));
-
-                    if result.as_ref().is_ok_and(|a| !a.passed(&expected_answer)) {
-                        return Err(RunError::ExampleWrongAnswer {
-                            part: Part::Both,
-                            expected: expected_answer,
-                            actual: result.unwrap(),
-                        });
-                    }
+        }
+        let [p_1, p_2] = parts;
+        Answer::from_parts(p_1, p_2)
+    } else {
+        page.expected_answer()
+    };
+    let answer = answer.ok_or_else(|| anyhow::anyhow!("the page has no answers"))?;
 
-                    if let Some(full) = full {
-                        let (result, time) =
-                            time_fn(|| solver.solve(full).map(|(a, b)| Answer::Both(a, b)));
+    let data_dir = config.data_dir();
+    let path = data_dir.join(import::example_path(day));
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, example)?;
+    println!("Wrote {} with {answer}", path.display());
 
-                        Ok(RunResult::Single(RunSingleResult { result, time }))
-                    } else {
-                        Ok(RunResult::Single(RunSingleResult { result, time }))
-                    }
-                }
-                (Solver::Combined(_), ExampleReader::Multi { .. }) => {
-                    Err(RunError::CombinedForSeparatedAnswer)
-                }
-                (
-                    Solver::Separated(solver),
-                    ExampleReader::Multi {
-                        file_1,
-                        expected_answer_1,
-                        file_2,
-                        expected_answer_2,
-                    },
-                ) => {
-                    let (r_1, t_1) = time_fn(|| solver.part_1(file_1));
-
-                    if r_1.as_ref().is_ok_and(|a| a != &expected_answer_1) {
-                        return Err(RunError::ExampleWrongAnswer {
-                            part: Part::P1,
-                            expected: Answer::P1(expected_answer_1),
-                            actual: Answer::P1(r_1.unwrap()),
-                        });
-                    }
-                    if r_1.is_err() {
-                        return Ok(RunResult::Single(RunSingleResult {
-                            result: r_1.map(Answer::P1),
-                            time: t_1,
-                        }));
-                    }
+    import::set_example(&mut meta, day, &data_dir, path, answer);
+    meta.save(&config.meta_path())?;
 
-                    let (r_2, t_2) = time_fn(|| solver.part_2(file_2));
-                    if r_2.as_ref().is_ok_and(|a| a != &expected_answer_2) {
-                        return Err(RunError::ExampleWrongAnswer {
-                            part: Part::P2,
-                            expected: Answer::P2(expected_answer_2),
-                            actual: Answer::P2(r_2.unwrap()),
-                        });
-                    }
+    Ok(())
+}
 
-                    if let Some(full) = full {
-                        let Ok(SourceReader::Example(_, Some(full_2))) =
-                            source.to_readers(&meta, day)
-                        else {
-                            unreachable!();
-                        };
-
-                        let (r_1, t_1) = time_fn(|| solver.part_1(full).map(Answer::P1));
-                        let (r_2, t_2) = time_fn(|| solver.part_2(full_2).map(Answer::P2));
-
-                        Ok(RunResult::Multi(
-                            RunSingleResult {
-                                result: r_1,
-                                time: t_1,
-                            },
-                            RunSingleResult {
-                                result: r_2,
-                                time: t_2,
-                            },
-                        ))
-                    } else {
-                        Ok(RunResult::Multi(
-                            RunSingleResult {
-                                result: r_1.map(Answer::P1),
-                                time: t_1,
-                            },
-                            RunSingleResult {
-                                result: r_2.map(Answer::P2),
-                                time: t_2,
-                            },
-                        ))
-                        // let r: day::Result = {
-                        //     match (r_1, r_2) {
-                        //         (Ok(a), Ok(b))
-                        //     }
-                        // }
-                    }
-                }
-                (
-                    Solver::Separated(solver),
-                    ExampleReader::Single {
-                        file,
-                        expected_answer,
-                    },
-                ) => {
-                    let Ok(SourceReader::Example(
-                        ExampleReader::Single { file: file_2, .. },
-                        full_2,
-                    )) = source.to_readers(&meta, day)
-                    else {
-                        unreachable!();
-                    };
-
-                    let (r_1, t_1) = time_fn(|| solver.part_1(file));
-
-                    let (r_2, t_2) = time_fn(|| solver.part_2(file_2));
-
-                    // dbg!(&r_1, &r_2, &expected_answer);
-
-                    match (r_1, r_2) {
-                        (Ok(a_1), Ok(a_2)) => {
-                            let answer = Answer::Both(a_1, a_2);
-                            if !answer.passed(&expected_answer) {
-                                return Err(RunError::ExampleWrongAnswer {
-                                    part: Part::Both,
-                                    expected: expected_answer,
-                                    actual: answer,
-                                });
-                            } else if let Some(full) = full {
-                                let (r_1, t_1) = time_fn(|| solver.part_1(full));
-
-                                let (r_2, t_2) = time_fn(|| solver.part_2(full_2.unwrap()));
-
-                                return Ok(RunResult::Multi(
-                                    RunSingleResult {
-                                        result: r_1.map(Answer::P1),
-                                        time: t_1,
-                                    },
-                                    RunSingleResult {
-                                        result: r_2.map(Answer::P2),
-                                        time: t_2,
-                                    },
-                                ));
-                            }
-                            let Answer::Both(a_1, a_2) = answer else {
-                                unreachable!()
-                            };
-                            let r_1 = Ok(a_1);
-                            let r_2 = Ok(a_2);
-
-                            Ok(RunResult::Multi(
-                                RunSingleResult {
-                                    result: r_1.map(Answer::P1),
-                                    time: t_1,
-                                },
-                                RunSingleResult {
-                                    result: r_2.map(Answer::P2),
-                                    time: t_2,
-                                },
-                            ))
-                        }
-                        (r_1, r_2) => Ok(RunResult::Multi(
-                            RunSingleResult {
-                                result: r_1.map(Answer::P1),
-                                time: t_1,
-                            },
-                            RunSingleResult {
-                                result: r_2.map(Answer::P2),
-                                time: t_2,
-                            },
-                        )),
-                    }
-                }
-            }
-        }
+/// Asks for a value on stdin, giving `default` for an empty line.
+fn prompt(question: &str, default: &str) -> io::Result<String> {
+    print!("{question} [{default}]: ");
+    io::stdout().flush()?;
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    let line = line.trim();
+    Ok(if line.is_empty() { default } else { line }.to_string())
+}
+
+fn run_profile(args: ProfileArgs, meta: &DaysMeta, budget: Option<Duration>) -> anyhow::Result<()> {
+    let ProfileArgs {
+        run:
+            RunArgs {
+                day,
+                part,
+                source,
+                variant,
+            },
+        duration,
+    } = args;
+    let solver = runner::find_solver(day, variant.as_deref(), meta)?;
+    Context::enter(day);
+    // budgets are for both parts on the full input
+    let budget =
+        day_budget(meta, day, budget).filter(|_| source.uses_full() && part == PartArgs::Both);
+
+    // read the input once so that only the solver shows up in the profile
+    let input = runner::read_input(day, &part, source, meta)?;
+
+    println!("Running {day} for {duration:.2?}...");
+    profile::take_spans();
+    let stats = profile::run_loop(&solver, &part, input.into(), duration)?;
+    println!("{day}: {stats}");
+    print_spans("Spans:");
+    if let Some(budget) = budget.filter(|budget| stats.median > *budget) {
+        anyhow::bail!(
+            "{day} is over budget: median {:.2?} > {budget:.2?}",
+            stats.median
+        );
     }
+
+    Ok(())
 }
 
-#[inline]
-fn time_fn<T>(f: impl FnOnce() -> T) -> (T, Duration) {
-    let now = Instant::now();
-    let result = f();
-    let time = now.elapsed();
-    (result, time)
+fn list(meta: &DaysMeta, year: Option<i32>) {
+    let yes_no = |b: bool| if b { "✓" } else { "✗" };
+    let parts = |p: [bool; 2]| match p {
+        [true, true] => "1&2",
+        [true, false] => "1",
+        [false, true] => "2",
+        [false, false] => "-",
+    };
+
+    println!(
+        "{:<8} {:<9} {:<6} {:<6} {:<7} {:<4} {:<8} {:<8}",
+        "Day", "Solver", "Part 1", "Part 2", "Example", "Full", "Expected", "Verified"
+    );
+    for status in status::statuses(meta, year) {
+        let (kind, p_1, p_2) = match &status.solver {
+            Some(solver) => (
+                format!("{:?}", solver.kind),
+                yes_no(solver.parts[0]),
+                yes_no(solver.parts[1]),
+            ),
+            None => ("-".to_string(), "-", "-"),
+        };
+        let meta = match &status.meta {
+            Some(meta) => format!(
+                "{:<7} {:<4} {:<8} {:<8}",
+                yes_no(meta.example),
+                yes_no(meta.full),
+                parts(meta.expected),
+                parts(meta.verified)
+            ),
+            None => "no meta".to_string(),
+        };
+        println!(
+            "{:<8} {:<9} {:<6} {:<6} {}",
+            status.day.to_string(),
+            kind,
+            p_1,
+            p_2,
+            meta.trim_end()
+        );
+    }
 }
 
-pub enum RunResult {
-    Single(RunSingleResult),
-    Multi(RunSingleResult, RunSingleResult),
+fn calendar(meta: &DaysMeta, year: Option<i32>) {
+    // a star for verified parts, a plus for parts that are solved but not verified yet
+    let part = |star: bool, solved: bool| {
+        if star {
+            "*".yellow().bold()
+        } else if solved {
+            "+".normal()
+        } else {
+            ".".dimmed()
+        }
+    };
+
+    let calendars = status::calendars(meta, year);
+    let mut total = (0, 0);
+    for calendar in &calendars {
+        println!(
+            "{}: {}/{} stars",
+            calendar.year,
+            calendar.stars(),
+            calendar.max_stars()
+        );
+        for week in calendar.days.chunks(5) {
+            let days = week
+                .iter()
+                .map(|d| {
+                    format!(
+                        "{:>2} {}{}",
+                        d.day,
+                        part(d.stars[0], d.solved[0]),
+                        part(d.stars[1], d.solved[1])
+                    )
+                })
+                .collect::<Vec<_>>();
+            println!("  {}", days.join("   "));
+        }
+        total = (total.0 + calendar.stars(), total.1 + calendar.max_stars());
+    }
+
+    if calendars.len() > 1 {
+        println!("Total: {}/{} stars", total.0, total.1);
+    }
+    println!(
+        "{} verified, + solved but not verified",
+        "*".yellow().bold()
+    );
 }
 
-impl Display for RunResult {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            RunResult::Single(r) => {
-                write!(f, "{}", r)
-            }
-            RunResult::Multi(r_1, r_2) => {
-                write!(f, "Result:\n  {}\n  {}", r_1, r_2)
-            }
+/// Encrypts every plain full input to its encrypted path, or decrypts it back.
+fn crypt_inputs(args: CryptArgs, meta: &DaysMeta, encrypt: bool) -> anyhow::Result<()> {
+    let CryptArgs { year, remove } = args;
+    let key = Key::load()?;
+
+    let mut count = 0;
+    for (day, info) in &meta.0 {
+        if year.is_some_and(|year| day.parse::<Day>().is_ok_and(|d| d.year != year)) {
+            continue;
+        }
+        let plain = info.full.as_path();
+        let encrypted = crypt::encrypted_path(plain);
+        let (from, to) = if encrypt {
+            (plain, encrypted.as_path())
+        } else {
+            (encrypted.as_path(), plain)
+        };
+        if !from.exists() {
+            continue;
         }
+
+        let contents = fs::read(from)?;
+        let converted = if encrypt {
+            key.encrypt(&contents)
+        } else {
+            key.decrypt(&contents)?
+        };
+        fs::write(to, converted)?;
+        if remove {
+            fs::remove_file(from)?;
+        }
+        println!("{day}: wrote {}", to.display());
+        count += 1;
     }
+
+    println!(
+        "{} {count} inputs",
+        if encrypt { "Encrypted" } else { "Decrypted" }
+    );
+    Ok(())
 }
 
-pub struct RunSingleResult {
-    pub result: day::Result,
-    pub time: Duration,
+fn visualize(args: VisualizeArgs, meta: &DaysMeta) -> anyhow::Result<()> {
+    let VisualizeArgs { run, fps, export } = args;
+    let frames = runner::visualize(run.day, &run.part, run.source, meta)?;
+    match export {
+        Some(dir) => {
+            let count = visualize::export(frames, &dir, 4)?;
+            println!("Wrote {count} frames to {}", dir.display());
+        }
+        None => visualize::play(frames, fps)?,
+    }
+
+    Ok(())
 }
 
-impl Display for RunSingleResult {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match &self.result {
-            Ok(a) => write!(f, "Obtained result: {} in {:.2?}", a, self.time),
-            Err(e) => write!(f, "Failed: {}", e),
+fn diff(args: RunArgs, meta: &DaysMeta) -> anyhow::Result<()> {
+    let RunArgs {
+        day, part, source, ..
+    } = args;
+    let parts = match part {
+        PartArgs::Both => vec![PartArgs::P1, PartArgs::P2],
+        part => vec![part],
+    };
+
+    let mut disagreements = 0;
+    let mut main_failed = 0;
+    for part in parts {
+        let input: Arc<[u8]> = runner::read_input(day, &part, source.clone(), meta)?.into();
+        let runs = runner::diff_variants(day, &part, &input);
+        let Some(main) = runs.first() else {
+            return Err(RunError::NoSolver(day).into());
+        };
+
+        println!("{day} part {}:", if part == PartArgs::P1 { 1 } else { 2 });
+        let width = runs.iter().map(|r| r.name.len()).max().unwrap_or(0);
+        if main.answer.is_err() {
+            main_failed += 1;
+        }
+        for (i, run) in runs.iter().enumerate() {
+            let mark = match (&run.answer, &main.answer) {
+                // without a main answer there's nothing to compare the variants to
+                (_, Err(_)) if i > 0 => "?".dimmed(),
+                (Ok(a), Ok(b)) if a == b => "✓".green(),
+                // the main solver itself failed
+                _ if i == 0 => "✗".red(),
+                _ => {
+                    disagreements += 1;
+                    "✗".red()
+                }
+            };
+            let answer = match &run.answer {
+                Ok(a) => a.indented(4).to_string(),
+                Err(e) => format!("failed: {e}"),
+            };
+            // a main solver too fast to measure has nothing to compare against
+            let ratio = if main.time.is_zero() {
+                "-".to_string()
+            } else {
+                format!("{:.2}x", run.time.as_secs_f64() / main.time.as_secs_f64())
+            };
+            println!(
+                "  {mark} {:width$}  {:>10.2?} ({ratio})  {answer}",
+                run.name, run.time
+            );
         }
     }
+
+    let problems = [
+        (main_failed, "parts failed in the main solver"),
+        (disagreements, "variant answers differ from the main solver"),
+    ]
+    .iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, what)| format!("{count} {what}"))
+    .collect::<Vec<_>>();
+    if !problems.is_empty() {
+        anyhow::bail!("{}", problems.join(", "));
+    }
+
+    Ok(())
 }
 
 // Requirements:
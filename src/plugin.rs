@@ -0,0 +1,202 @@
+//! Solvers loaded from shared libraries in the plugins directory, see the `aoc-plugin` crate for
+//! the ABI they implement.
+
+use crate::cache;
+use crate::day::{Day, SeparatedSolver, Solver};
+use anyhow::{bail, Context};
+use aoc_plugin::{RegisterFn, SolveFn, VersionFn, ABI_VERSION, REGISTER_SYMBOL, VERSION_SYMBOL};
+use libloading::Library;
+use std::collections::BTreeMap;
+use std::ffi::c_void;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::{env, fs, slice};
+
+/// A loaded plugin, which has to stay loaded for as long as its solvers are used.
+pub struct Plugin {
+    pub path: PathBuf,
+    /// Hash of the library, standing in for the source fingerprint of its days
+    pub fingerprint: u64,
+    /// Every day the plugin solves at least one part of
+    pub days: Vec<Day>,
+    solvers: BTreeMap<Day, PluginSolver>,
+    _library: Library,
+}
+
+impl Plugin {
+    /// Loads the library at `path` and collects the parts it registers.
+    ///
+    /// # Safety
+    ///
+    /// Loading a library runs its initialization code, and its entry points are trusted to
+    /// follow the ABI.
+    pub unsafe fn load(path: &Path) -> anyhow::Result<Self> {
+        let bytes = fs::read(path)?;
+        let library =
+            Library::new(path).with_context(|| format!("couldn't load {}", path.display()))?;
+
+        let version = library
+            .get::<VersionFn>(VERSION_SYMBOL)
+            .with_context(|| format!("{} is not a plugin", path.display()))?;
+        let version = version();
+        if version != ABI_VERSION {
+            bail!(
+                "{} is built for plugin ABI {version}, expected {ABI_VERSION}",
+                path.display()
+            );
+        }
+        let register = *library.get::<RegisterFn>(REGISTER_SYMBOL)?;
+
+        let solvers = collect(register);
+        Ok(Self {
+            path: path.to_path_buf(),
+            fingerprint: cache::hash(&bytes),
+            days: solvers.keys().copied().collect(),
+            solvers,
+            _library: library,
+        })
+    }
+
+    /// Hands over the solvers, which stay valid while the plugin is kept.
+    pub fn take_solvers(&mut self) -> Vec<(Day, Solver)> {
+        std::mem::take(&mut self.solvers)
+            .into_iter()
+            .map(|(day, solver)| (day, Solver::separated(solver)))
+            .collect()
+    }
+}
+
+/// Loads every library in `dir`, in order of their names. A missing directory has no plugins.
+///
+/// # Safety
+///
+/// See [`Plugin::load`].
+pub unsafe fn load_dir(dir: &Path) -> anyhow::Result<Vec<Plugin>> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(Vec::new());
+    };
+    let mut paths = entries
+        .map(|e| Ok(e?.path()))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    paths.retain(|p| {
+        p.extension()
+            .is_some_and(|e| e == env::consts::DLL_EXTENSION)
+    });
+    paths.sort();
+
+    paths.iter().map(|path| Plugin::load(path)).collect()
+}
+
+/// Calls a plugin's `register` and groups the parts it adds by day.
+unsafe fn collect(register: RegisterFn) -> BTreeMap<Day, PluginSolver> {
+    unsafe extern "C" fn add_part(
+        registrar: *mut c_void,
+        day: i32,
+        year: i32,
+        part: u8,
+        solve: SolveFn,
+        data: *const c_void,
+    ) {
+        let solvers = &mut *(registrar as *mut BTreeMap<Day, PluginSolver>);
+        let index = match part {
+            1 => 0,
+            2 => 1,
+            _ => return,
+        };
+        let solver = solvers.entry(Day::new(day, year)).or_default();
+        solver.parts[index] = Some(PluginPart { solve, data });
+    }
+
+    let mut solvers = BTreeMap::new();
+    register(&mut solvers as *mut _ as *mut c_void, add_part);
+    solvers
+}
+
+#[derive(Default)]
+struct PluginSolver {
+    parts: [Option<PluginPart>; 2],
+}
+
+struct PluginPart {
+    solve: SolveFn,
+    data: *const c_void,
+}
+
+// `data` points into the plugin, which lives as long as the solver and is called from any thread
+unsafe impl Send for PluginPart {}
+unsafe impl Sync for PluginPart {}
+
+impl PluginPart {
+    fn run(&self, mut input: Box<dyn BufRead>) -> anyhow::Result<String> {
+        unsafe extern "C" fn write(out: *mut c_void, ptr: *const u8, len: usize) {
+            (*(out as *mut Vec<u8>)).extend_from_slice(slice::from_raw_parts(ptr, len));
+        }
+
+        let mut bytes = Vec::new();
+        input.read_to_end(&mut bytes)?;
+        let mut out = Vec::<u8>::new();
+        let status = unsafe {
+            (self.solve)(
+                self.data,
+                bytes.as_ptr(),
+                bytes.len(),
+                &mut out as *mut _ as *mut c_void,
+                write,
+            )
+        };
+
+        let text = String::from_utf8(out)?;
+        if status != 0 {
+            bail!(text);
+        }
+        Ok(text)
+    }
+}
+
+impl PluginSolver {
+    fn run(&self, part: usize, input: Box<dyn BufRead>) -> anyhow::Result<String> {
+        match &self.parts[part - 1] {
+            Some(solver) => solver.run(input),
+            None => bail!("part {part} is not implemented"),
+        }
+    }
+}
+
+impl SeparatedSolver for PluginSolver {
+    fn part_1(&self, input: Box<dyn BufRead>) -> anyhow::Result<String> {
+        self.run(1, input)
+    }
+
+    fn part_2(&self, input: Box<dyn BufRead>) -> anyhow::Result<String> {
+        self.run(2, input)
+    }
+
+    fn has_part_1(&self) -> bool {
+        self.parts[0].is_some()
+    }
+
+    fn has_part_2(&self) -> bool {
+        self.parts[1].is_some()
+    }
+}
+
+#[test]
+fn should_call_registered_parts() {
+    unsafe extern "C" fn register(registrar: *mut c_void, add_part: aoc_plugin::AddPartFn) {
+        static PART: &aoc_plugin::Part = &|input: &str| match input.trim().parse::<u32>() {
+            Ok(n) => Ok((n * 2).to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        let data = &PART as *const &aoc_plugin::Part as *const c_void;
+        add_part(registrar, 3, 2024, 2, aoc_plugin::solve, data);
+    }
+
+    // when
+    let solvers = unsafe { collect(register) };
+    let solver = &solvers[&Day::new(3, 2024)];
+    // then
+    assert!(!solver.has_part_1());
+    assert!(solver.has_part_2());
+    assert_eq!("42", solver.part_2(Box::new("21\n".as_bytes())).unwrap());
+    assert!(solver.part_2(Box::new("x".as_bytes())).is_err());
+}
@@ -0,0 +1,161 @@
+use crate::cli::PartArgs;
+use crate::day::Solver;
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::io::Cursor;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+static SPANS: Mutex<Vec<(&'static str, SpanStats)>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct SpanStats {
+    pub calls: u64,
+    pub total: Duration,
+}
+
+/// Times a phase of a solver until the returned guard is dropped.
+///
+/// ```ignore
+/// let _span = profile::span("parse");
+/// ```
+#[must_use = "the span ends when the guard is dropped"]
+pub fn span(name: &'static str) -> Span {
+    Span {
+        name,
+        start: Instant::now(),
+    }
+}
+
+pub struct Span {
+    name: &'static str,
+    start: Instant,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        let mut spans = SPANS.lock().unwrap();
+        let stats = match spans.iter().position(|(name, _)| *name == self.name) {
+            Some(i) => &mut spans[i].1,
+            None => {
+                spans.push((self.name, SpanStats::default()));
+                &mut spans.last_mut().unwrap().1
+            }
+        };
+        stats.calls += 1;
+        stats.total += elapsed;
+    }
+}
+
+/// Removes and returns every span recorded so far, in the order they were first seen.
+pub fn take_spans() -> SpanReport {
+    SpanReport(std::mem::take(&mut *SPANS.lock().unwrap()))
+}
+
+#[derive(Default)]
+pub struct SpanReport(pub Vec<(&'static str, SpanStats)>);
+
+impl SpanReport {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SpanStats> {
+        self.0.iter().find(|(n, _)| *n == name).map(|(_, s)| s)
+    }
+}
+
+impl Display for SpanReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let width = self.0.iter().map(|(k, _)| k.len()).max().unwrap_or(0);
+        for (name, stats) in self.0.iter() {
+            write!(f, "\n  {:width$}  {:>10.2?}", name, stats.total)?;
+            if stats.calls > 1 {
+                write!(
+                    f,
+                    "  ({} calls, {:.2?} each)",
+                    stats.calls,
+                    stats.total / stats.calls as u32
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct LoopStats {
+    pub iterations: u32,
+    pub total: Duration,
+    pub min: Duration,
+    pub median: Duration,
+}
+
+impl Display for LoopStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} iterations in {:.2?} (mean {:.2?}, median {:.2?}, min {:.2?})",
+            self.iterations,
+            self.total,
+            self.total / self.iterations.max(1),
+            self.median,
+            self.min
+        )
+    }
+}
+
+/// Runs a part over and over on the same input until `duration` has passed,
+/// so that a sampling profiler has something to attach to.
+pub fn run_loop(
+    solver: &Solver,
+    part: &PartArgs,
+    input: Arc<[u8]>,
+    duration: Duration,
+) -> anyhow::Result<LoopStats> {
+    let reader = || Box::new(Cursor::new(input.clone()));
+    let mut times = Vec::new();
+    let start = Instant::now();
+
+    while times.is_empty() || start.elapsed() < duration {
+        let now = Instant::now();
+        match (solver, part) {
+            (Solver::Combined(solver), _) => {
+                black_box(solver.answer(reader())?);
+            }
+            (Solver::Separated(solver), PartArgs::P1) => {
+                black_box(solver.answer_part_1(reader())?);
+            }
+            (Solver::Separated(solver), PartArgs::P2) => {
+                black_box(solver.answer_part_2(reader())?);
+            }
+            (Solver::Separated(solver), PartArgs::Both) => {
+                black_box(solver.answer_part_1(reader())?);
+                black_box(solver.answer_part_2(reader())?);
+            }
+        }
+        times.push(now.elapsed());
+    }
+
+    times.sort_unstable();
+    Ok(LoopStats {
+        iterations: times.len() as u32,
+        total: times.iter().sum(),
+        min: times[0],
+        median: times[times.len() / 2],
+    })
+}
+
+#[test]
+fn should_record_spans() {
+    // given
+    take_spans();
+    // when
+    for _ in 0..3 {
+        let _span = span("test-span");
+    }
+    let report = take_spans();
+    // then
+    assert_eq!(3, report.get("test-span").unwrap().calls);
+    assert!(take_spans().is_empty());
+}
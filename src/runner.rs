@@ -0,0 +1,1005 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::io::{BufRead, Cursor, Read};
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::alloc::AllocStats;
+use crate::cache::{self, ResultCache};
+use crate::cli::{ExampleReader, PartArgs, ReadersError, RunSource, SingleSource, SourceReader};
+use crate::context::{Context, Input};
+use crate::day::{
+    Answer, AnswerValue, AnswerWarning, Day, DayInfo, DaysMeta, Solver, SolverDatabase, Verdict,
+    MAIN_VARIANT,
+};
+use crate::diff::AnswerDiff;
+use crate::isolate::Isolation;
+use crate::profile::SpanReport;
+use crate::visualize::Frames;
+use crate::{alloc, crypt, day, profile};
+
+#[derive(Error, Debug)]
+#[error("No solver for {0}")]
+pub struct NoSolver(pub Day);
+
+#[derive(Error, Debug)]
+#[error("No generator for {0}")]
+pub struct NoGenerator(pub Day);
+
+#[derive(Error, Debug)]
+#[error("No visualization for {0}")]
+pub struct NoVisualizer(pub Day);
+
+/// The solver found for a day, either compiled in or an external command from the meta.
+pub enum DaySolver {
+    Native(&'static Solver),
+    External(Solver),
+}
+
+impl DaySolver {
+    /// The solver for `day`, falling back to the command in its meta `info`.
+    pub fn find(day: Day, info: Option<&DayInfo>) -> Option<Self> {
+        SolverDatabase::global()
+            .get_solver(&day)
+            .map(DaySolver::Native)
+            .or_else(|| {
+                let command = info?.command.clone()?;
+                Some(DaySolver::External(Solver::combined(command)))
+            })
+    }
+}
+
+impl Deref for DaySolver {
+    type Target = Solver;
+
+    fn deref(&self) -> &Solver {
+        match self {
+            DaySolver::Native(solver) => solver,
+            DaySolver::External(solver) => solver,
+        }
+    }
+}
+
+/// One day run on its full input by [`run_all`].
+pub struct DayRun {
+    pub day: Day,
+    pub result: DayResult,
+    /// Problems with the answers found from the rejected answers in the meta
+    pub warnings: Vec<AnswerWarning>,
+    pub spans: SpanReport,
+    /// Whether the result was taken from the [`ResultCache`] rather than run
+    pub cached: bool,
+}
+
+impl DayRun {
+    /// The `budget` if the day took longer than it. Cached days are never over budget, since
+    /// their times are from whichever build cached them.
+    pub fn over_budget(&self, budget: Option<Duration>) -> Option<Duration> {
+        budget.filter(|budget| !self.cached && self.result.time() > *budget)
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub enum DayResult {
+    /// Both parts came out of a single call, so there is only one time.
+    Combined {
+        #[serde(with = "placeholder::pair")]
+        answer: (AnswerValue, AnswerValue),
+        time: Duration,
+        #[serde(skip)]
+        alloc: Option<AllocStats>,
+    },
+    Separated([PartResult; 2]),
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PartResult {
+    #[serde(with = "placeholder")]
+    pub answer: AnswerValue,
+    pub time: Duration,
+    #[serde(skip)]
+    pub alloc: Option<AllocStats>,
+}
+
+/// Writes [`AnswerValue::Unimplemented`] as `null`, so that results passed between processes or
+/// cached keep their placeholder parts. Answers in the manifest are always strings.
+mod placeholder {
+    use crate::day::AnswerValue;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    fn to_option(value: &AnswerValue) -> Option<String> {
+        value.is_implemented().then(|| value.to_string())
+    }
+
+    fn from_option(value: Option<String>) -> AnswerValue {
+        value.map_or(AnswerValue::Unimplemented, Into::into)
+    }
+
+    pub fn serialize<S: Serializer>(value: &AnswerValue, serializer: S) -> Result<S::Ok, S::Error> {
+        to_option(value).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<AnswerValue, D::Error> {
+        Option::deserialize(deserializer).map(from_option)
+    }
+
+    pub mod pair {
+        use super::{from_option, to_option};
+        use crate::day::AnswerValue;
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            (p_1, p_2): &(AnswerValue, AnswerValue),
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            (to_option(p_1), to_option(p_2)).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<(AnswerValue, AnswerValue), D::Error> {
+            let (p_1, p_2) = Deserialize::deserialize(deserializer)?;
+            Ok((from_option(p_1), from_option(p_2)))
+        }
+    }
+}
+
+impl DayResult {
+    pub fn answer(&self) -> Answer {
+        match self {
+            DayResult::Combined {
+                answer: (p_1, p_2), ..
+            } => Answer::Both(p_1.clone(), p_2.clone()),
+            DayResult::Separated([p_1, p_2]) => {
+                Answer::Both(p_1.answer.clone(), p_2.answer.clone())
+            }
+        }
+    }
+
+    pub fn time(&self) -> Duration {
+        match self {
+            DayResult::Combined { time, .. } => *time,
+            DayResult::Separated([p_1, p_2]) => p_1.time + p_2.time,
+        }
+    }
+
+    /// The first of `results`, of runs of the same day, with the median time of each part.
+    pub fn median(results: Vec<DayResult>) -> DayResult {
+        let median = |time: &dyn Fn(&DayResult) -> Duration| {
+            let mut times = results.iter().map(time).collect_vec();
+            times.sort_unstable();
+            times[times.len() / 2]
+        };
+        let part_time = |part: usize| {
+            move |result: &DayResult| match result {
+                DayResult::Separated(parts) => parts[part].time,
+                result => result.time(),
+            }
+        };
+        match &results[0] {
+            DayResult::Combined { answer, alloc, .. } => DayResult::Combined {
+                answer: answer.clone(),
+                time: median(&DayResult::time),
+                alloc: *alloc,
+            },
+            DayResult::Separated([p_1, p_2]) => DayResult::Separated([
+                PartResult {
+                    time: median(&part_time(0)),
+                    ..p_1.clone()
+                },
+                PartResult {
+                    time: median(&part_time(1)),
+                    ..p_2.clone()
+                },
+            ]),
+        }
+    }
+}
+
+/// Runs every day in the meta on its full input, in order, as the iterator is advanced.
+///
+/// With a `cache`, days whose solver and input didn't change since they were cached aren't run
+/// again, and new results are added to it. With an `isolation`, each day runs in a child process.
+/// Each day runs `runs` times, keeping the median times.
+pub fn run_all<'a>(
+    meta: &'a DaysMeta,
+    mut cache: Option<&'a mut ResultCache>,
+    isolation: Option<&'a Isolation>,
+    runs: usize,
+) -> impl Iterator<Item = anyhow::Result<DayRun>> + 'a {
+    let mut days: Vec<(Day, &DayInfo)> = meta
+        .0
+        .iter()
+        .map(|(s, i)| {
+            let day = s.parse().unwrap();
+            (day, i)
+        })
+        .collect_vec();
+
+    days.sort_unstable_by_key(|x| x.0);
+
+    days.into_iter().map(move |(day, info)| {
+        let run_once = |day, info: &DayInfo| match isolation {
+            Some(isolation) => isolation.run(day, info),
+            None => run_full(day, info),
+        };
+        let run = |day, info: &DayInfo| {
+            let mut run = run_once(day, info)?;
+            if runs > 1 {
+                let mut results = vec![run.result.clone()];
+                for _ in 1..runs {
+                    results.push(run_once(day, info)?.result);
+                }
+                run.result = DayResult::median(results);
+            }
+            Ok(run)
+        };
+        match cache.as_deref_mut() {
+            Some(cache) => run_cached(day, info, cache, run),
+            None => run(day, info),
+        }
+    })
+}
+
+fn run_cached(
+    day: Day,
+    info: &DayInfo,
+    cache: &mut ResultCache,
+    run: impl FnOnce(Day, &DayInfo) -> anyhow::Result<DayRun>,
+) -> anyhow::Result<DayRun> {
+    let input = cache::hash(&crypt::read(&info.full)?);
+    if let Some(result) = cache.get(day, input) {
+        return Ok(DayRun {
+            day,
+            warnings: info.check_answer(&result.answer()),
+            result: result.clone(),
+            spans: SpanReport::default(),
+            cached: true,
+        });
+    }
+
+    let run = run(day, info)?;
+    cache.insert(day, input, &run.result);
+    Ok(run)
+}
+
+/// Runs both parts of `day` on its full input, checking them against the rejected answers.
+pub fn run_full(day: Day, info: &DayInfo) -> anyhow::Result<DayRun> {
+    Context::enter(day);
+    Context::enter_input(Input::Full);
+    let open = || crypt::open(&info.full);
+
+    let solver = DaySolver::find(day, Some(info)).ok_or(NoSolver(day))?;
+    let result = match &*solver {
+        Solver::Combined(solver) => {
+            let full = open()?;
+            let (r, time, alloc) = time_fn(|| solver.answer(full));
+            let (p_1, p_2) = r?;
+            DayResult::Combined {
+                answer: (p_1, p_2),
+                time,
+                alloc,
+            }
+        }
+        Solver::Separated(solver) => {
+            let full = open()?;
+            let (r_1, t_1, m_1) = time_fn(|| solver.answer_part_1(full));
+            let a_1 = r_1?;
+
+            let full = open()?;
+            let (r_2, t_2, m_2) = time_fn(|| solver.answer_part_2(full));
+            let a_2 = r_2?;
+
+            DayResult::Separated([
+                PartResult {
+                    answer: a_1,
+                    time: t_1,
+                    alloc: m_1,
+                },
+                PartResult {
+                    answer: a_2,
+                    time: t_2,
+                    alloc: m_2,
+                },
+            ])
+        }
+    };
+
+    Ok(DayRun {
+        day,
+        warnings: info.check_answer(&result.answer()),
+        result,
+        spans: profile::take_spans(),
+        cached: false,
+    })
+}
+
+/// Solves a single part on the full input, without checking it against anything.
+pub fn solve_part(day: Day, part: &PartArgs, meta: &DaysMeta) -> anyhow::Result<AnswerValue> {
+    let solver = DaySolver::find(day, meta.get_day(day)).ok_or(NoSolver(day))?;
+    Context::enter(day);
+    Context::enter_input(Input::Full);
+    let SourceReader::Simple(reader) = RunSource::Single(SingleSource::Full)
+        .to_readers(meta, day)
+        .map_err(RunError::from)?
+    else {
+        unreachable!()
+    };
+
+    match (&*solver, part) {
+        (Solver::Combined(solver), PartArgs::P1) => solver.answer(reader).map(|(p_1, _)| p_1),
+        (Solver::Combined(solver), _) => solver.answer(reader).map(|(_, p_2)| p_2),
+        (Solver::Separated(solver), PartArgs::P1) => solver.answer_part_1(reader),
+        (Solver::Separated(solver), _) => solver.answer_part_2(reader),
+    }
+}
+
+/// Solves `part` of `day`, calling `open` for the `input` of each solver call.
+///
+/// Combined solvers are only called once, but only the asked for part is kept.
+pub fn solve<R: BufRead + 'static>(
+    day: Day,
+    info: &DayInfo,
+    part: &PartArgs,
+    input: Input,
+    open: impl Fn() -> io::Result<R>,
+) -> anyhow::Result<Answer> {
+    let solver = DaySolver::find(day, Some(info)).ok_or(NoSolver(day))?;
+    Context::enter(day);
+    Context::enter_input(input);
+    solve_with(&solver, part, open)
+}
+
+/// [`solve`] with a specific solver, e.g. one of the variants of a day.
+pub fn solve_with<R: BufRead + 'static>(
+    solver: &Solver,
+    part: &PartArgs,
+    open: impl Fn() -> io::Result<R>,
+) -> anyhow::Result<Answer> {
+    let input = || -> io::Result<Box<dyn BufRead>> { Ok(Box::new(open()?)) };
+
+    Ok(match (solver, part) {
+        (Solver::Combined(solver), part) => {
+            let (p_1, p_2) = solver.answer(input()?)?;
+            match part {
+                PartArgs::P1 => Answer::p1(p_1),
+                PartArgs::P2 => Answer::p2(p_2),
+                PartArgs::Both => Answer::both(p_1, p_2),
+            }
+        }
+        (Solver::Separated(solver), PartArgs::P1) => Answer::p1(solver.answer_part_1(input()?)?),
+        (Solver::Separated(solver), PartArgs::P2) => Answer::p2(solver.answer_part_2(input()?)?),
+        (Solver::Separated(solver), PartArgs::Both) => Answer::both(
+            solver.answer_part_1(input()?)?,
+            solver.answer_part_2(input()?)?,
+        ),
+    })
+}
+
+/// Looks up the solver for `day`, or one of its named variants.
+///
+/// Days without a solver in Rust use the command from the meta, which has no variants.
+pub fn find_solver(
+    day: Day,
+    variant: Option<&str>,
+    meta: &DaysMeta,
+) -> Result<DaySolver, RunError> {
+    let database = SolverDatabase::global();
+    match variant {
+        None | Some(MAIN_VARIANT) => {
+            DaySolver::find(day, meta.get_day(day)).ok_or(RunError::NoSolver(day))
+        }
+        Some(name) if database.get_solver(&day).is_some() => database
+            .get_variant(&day, Some(name))
+            .map(DaySolver::Native)
+            .ok_or_else(|| RunError::NoVariant(day, name.to_string())),
+        Some(_) => Err(RunError::NoSolver(day)),
+    }
+}
+
+/// One variant's answer to a part in [`diff_variants`].
+pub struct VariantRun {
+    pub name: &'static str,
+    pub answer: anyhow::Result<AnswerValue>,
+    pub time: Duration,
+}
+
+/// Runs `part` of every variant of `day` on the same input, main solver first.
+pub fn diff_variants(day: Day, part: &PartArgs, input: &Arc<[u8]>) -> Vec<VariantRun> {
+    Context::enter(day);
+    SolverDatabase::global()
+        .variants(&day)
+        .into_iter()
+        .map(|(name, solver)| {
+            let open = || Ok(Cursor::new(input.clone()));
+            let (answer, time, _) = time_fn(|| solve_with(solver, part, open));
+            let answer = answer.and_then(|a| {
+                let value = match part {
+                    PartArgs::P2 => a.part_2(),
+                    _ => a.part_1(),
+                };
+                value
+                    .cloned()
+                    .ok_or_else(|| anyhow::anyhow!("the variant didn't answer the part"))
+            });
+            VariantRun { name, answer, time }
+        })
+        .collect()
+}
+
+/// Generates a random input for `day`; the same seed and size always give the same input.
+pub fn generate(day: Day, seed: u64, size: usize) -> Result<String, NoGenerator> {
+    let generator = SolverDatabase::global()
+        .get_generator(&day)
+        .ok_or(NoGenerator(day))?;
+    let mut rng = StdRng::seed_from_u64(seed);
+    Ok(generator.generate(&mut rng, size))
+}
+
+/// The frames of `day`'s visualization of the input from `source`.
+pub fn visualize(
+    day: Day,
+    part: &PartArgs,
+    source: RunSource,
+    meta: &DaysMeta,
+) -> anyhow::Result<Frames> {
+    let visualizer = SolverDatabase::global()
+        .get_visualizer(&day)
+        .ok_or(NoVisualizer(day))?;
+    Context::enter(day);
+    let input = read_input(day, part, source, meta)?;
+    visualizer.frames(Box::new(Cursor::new(input)))
+}
+
+/// Reads the whole input `source` would give the solver for `part`, so it can be run more than once,
+/// and marks it as the input solvers are given.
+pub fn read_input(
+    day: Day,
+    part: &PartArgs,
+    source: RunSource,
+    meta: &DaysMeta,
+) -> Result<Vec<u8>, RunError> {
+    Context::enter_input(input_of(&source));
+    let mut reader = match source.to_readers(meta, day)? {
+        SourceReader::Simple(reader) | SourceReader::Example(_, Some(reader)) => reader,
+        SourceReader::Example(ExampleReader::Single { file, .. }, None) => file,
+        SourceReader::Example(ExampleReader::Multi { file_1, file_2, .. }, None) => {
+            if *part == PartArgs::P2 {
+                file_2
+            } else {
+                file_1
+            }
+        }
+    };
+    let mut input = Vec::new();
+    reader.read_to_end(&mut input)?;
+    Ok(input)
+}
+
+/// Which input `source` is, for [`Context::input`].
+fn input_of(source: &RunSource) -> Input {
+    match source {
+        RunSource::Single(SingleSource::Full) => Input::Full,
+        RunSource::Single(_) => Input::Other,
+        RunSource::Example(_) => Input::Example,
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum RunError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("no solver for {0}")]
+    NoSolver(Day),
+    #[error("no variant of {0} called {1:?}")]
+    NoVariant(Day, String),
+    #[error("no meta for {0}")]
+    NoMeta(Day),
+    #[error(
+        "wrong answer for example in part {part}:\n{}",
+        AnswerDiff::new(.expected, .actual)
+    )]
+    ExampleWrongAnswer {
+        part: Part,
+        expected: Box<Answer>,
+        actual: Box<Answer>,
+    },
+    #[error("part {0} is not implemented, so its example can't be checked")]
+    ExampleUnimplemented(u8),
+    #[error("Used a combined solver for a separated example")]
+    CombinedForSeparatedAnswer,
+}
+
+impl From<ReadersError> for RunError {
+    fn from(e: ReadersError) -> Self {
+        match e {
+            ReadersError::NoMeta(day) => RunError::NoMeta(day),
+            ReadersError::Io(io) => io.into(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Part {
+    P1,
+    P2,
+    Both,
+}
+
+/// Fails unless `actual` is the `expected` answer to the example, naming placeholder parts.
+fn check_example(part: Part, actual: &Answer, expected: Answer) -> Result<(), RunError> {
+    match actual.check(&expected) {
+        Verdict::Passed => Ok(()),
+        Verdict::Unimplemented(level) => Err(RunError::ExampleUnimplemented(level)),
+        Verdict::Wrong => Err(RunError::ExampleWrongAnswer {
+            part,
+            expected: Box::new(expected),
+            actual: Box::new(actual.clone()),
+        }),
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Part::P1 => "1",
+                Part::P2 => "2",
+                Part::Both => "1&2",
+            }
+        )
+    }
+}
+
+// Situations needed to handle:
+// Run only part 1 (example and full)
+// Run only part 2 (example and full)
+// Run both part 1 and 2
+/// Runs both parts of `day` on the input from `source`, checking example answers against the meta.
+pub fn run_day(
+    day: Day,
+    variant: Option<&str>,
+    source: RunSource,
+    meta: &DaysMeta,
+) -> Result<RunResult, RunError> {
+    let solver = find_solver(day, variant, meta)?;
+    Context::enter(day);
+    Context::enter_input(input_of(&source));
+
+    let reader = source.clone().to_readers(meta, day)?;
+
+    match reader {
+        SourceReader::Simple(reader) => match &*solver {
+            Solver::Combined(solver) => {
+                let (result, time, alloc) = time_fn(|| {
+                    solver
+                        .answer(reader)
+                        .map(|(s_1, s_2)| Answer::both(s_1, s_2))
+                });
+                Ok(RunResult::Single(RunSingleResult {
+                    result,
+                    time,
+                    alloc,
+                }))
+            }
+            Solver::Separated(solver) => {
+                let SourceReader::Simple(reader_2) = source.to_readers(meta, day).unwrap() else {
+                    unreachable!()
+                };
+
+                let (result_1, time_1, alloc_1) = time_fn(|| solver.answer_part_1(reader));
+
+                Ok(match result_1 {
+                    Ok(part_1) => {
+                        let (result_2, time_2, alloc_2) =
+                            time_fn(|| solver.answer_part_2(reader_2).map(Answer::p2));
+
+                        RunResult::Multi(
+                            RunSingleResult {
+                                result: Ok(Answer::p1(part_1)),
+                                time: time_1,
+                                alloc: alloc_1,
+                            },
+                            RunSingleResult {
+                                result: result_2,
+                                time: time_2,
+                                alloc: alloc_2,
+                            },
+                        )
+                    }
+                    Err(e) => RunResult::Single(RunSingleResult {
+                        result: Err(e),
+                        time: time_1,
+                        alloc: alloc_1,
+                    }),
+                })
+            }
+        },
+        SourceReader::Example(example, full) => {
+            match (&*solver, example) {
+                (
+                    Solver::Combined(solver),
+                    ExampleReader::Single {
+                        file,
+                        expected_answer,
+                    },
+                ) => {
+                    let (result, time, alloc) =
+                        time_fn(|| solver.answer(file).map(|(s_1, s_2)| Answer::both(s_1, s_2)));
+
+                    if let Ok(actual) = &result {
+                        check_example(Part::Both, actual, expected_answer)?;
+                    }
+
+                    if let Some(full) = full {
+                        Context::enter_input(Input::Full);
+                        let (result, time, alloc) =
+                            time_fn(|| solver.answer(full).map(|(a, b)| Answer::both(a, b)));
+
+                        Ok(RunResult::Single(RunSingleResult {
+                            result,
+                            time,
+                            alloc,
+                        }))
+                    } else {
+                        Ok(RunResult::Single(RunSingleResult {
+                            result,
+                            time,
+                            alloc,
+                        }))
+                    }
+                }
+                (Solver::Combined(_), ExampleReader::Multi { .. }) => {
+                    Err(RunError::CombinedForSeparatedAnswer)
+                }
+                (
+                    Solver::Separated(solver),
+                    ExampleReader::Multi {
+                        file_1,
+                        expected_answer_1,
+                        file_2,
+                        expected_answer_2,
+                    },
+                ) => {
+                    let (r_1, t_1, m_1) = time_fn(|| solver.answer_part_1(file_1));
+
+                    if let Ok(actual) = &r_1 {
+                        let actual = Answer::P1(actual.clone());
+                        check_example(Part::P1, &actual, Answer::P1(expected_answer_1))?;
+                    }
+                    if r_1.is_err() {
+                        return Ok(RunResult::Single(RunSingleResult {
+                            result: r_1.map(Answer::p1),
+                            time: t_1,
+                            alloc: m_1,
+                        }));
+                    }
+
+                    let (r_2, t_2, m_2) = time_fn(|| solver.answer_part_2(file_2));
+                    if let Ok(actual) = &r_2 {
+                        let actual = Answer::P2(actual.clone());
+                        check_example(Part::P2, &actual, Answer::P2(expected_answer_2))?;
+                    }
+
+                    if let Some(full) = full {
+                        Context::enter_input(Input::Full);
+                        let Ok(SourceReader::Example(_, Some(full_2))) =
+                            source.to_readers(meta, day)
+                        else {
+                            unreachable!();
+                        };
+
+                        let (r_1, t_1, m_1) =
+                            time_fn(|| solver.answer_part_1(full).map(Answer::p1));
+                        let (r_2, t_2, m_2) =
+                            time_fn(|| solver.answer_part_2(full_2).map(Answer::p2));
+
+                        Ok(RunResult::Multi(
+                            RunSingleResult {
+                                result: r_1,
+                                time: t_1,
+                                alloc: m_1,
+                            },
+                            RunSingleResult {
+                                result: r_2,
+                                time: t_2,
+                                alloc: m_2,
+                            },
+                        ))
+                    } else {
+                        Ok(RunResult::Multi(
+                            RunSingleResult {
+                                result: r_1.map(Answer::p1),
+                                time: t_1,
+                                alloc: m_1,
+                            },
+                            RunSingleResult {
+                                result: r_2.map(Answer::p2),
+                                time: t_2,
+                                alloc: m_2,
+                            },
+                        ))
+                        // let r: day::Result = {
+                        //     match (r_1, r_2) {
+                        //         (Ok(a), Ok(b))
+                        //     }
+                        // }
+                    }
+                }
+                (
+                    Solver::Separated(solver),
+                    ExampleReader::Single {
+                        file,
+                        expected_answer,
+                    },
+                ) => {
+                    let Ok(SourceReader::Example(
+                        ExampleReader::Single { file: file_2, .. },
+                        full_2,
+                    )) = source.to_readers(meta, day)
+                    else {
+                        unreachable!();
+                    };
+
+                    let (r_1, t_1, m_1) = time_fn(|| solver.answer_part_1(file));
+
+                    let (r_2, t_2, m_2) = time_fn(|| solver.answer_part_2(file_2));
+
+                    // dbg!(&r_1, &r_2, &expected_answer);
+
+                    match (r_1, r_2) {
+                        (Ok(a_1), Ok(a_2)) => {
+                            let answer = Answer::both(a_1, a_2);
+                            check_example(Part::Both, &answer, expected_answer)?;
+                            if let Some(full) = full {
+                                Context::enter_input(Input::Full);
+                                let (r_1, t_1, m_1) = time_fn(|| solver.answer_part_1(full));
+
+                                let (r_2, t_2, m_2) =
+                                    time_fn(|| solver.answer_part_2(full_2.unwrap()));
+
+                                return Ok(RunResult::Multi(
+                                    RunSingleResult {
+                                        result: r_1.map(Answer::p1),
+                                        time: t_1,
+                                        alloc: m_1,
+                                    },
+                                    RunSingleResult {
+                                        result: r_2.map(Answer::p2),
+                                        time: t_2,
+                                        alloc: m_2,
+                                    },
+                                ));
+                            }
+                            let Answer::Both(a_1, a_2) = answer else {
+                                unreachable!()
+                            };
+                            let r_1 = Ok(a_1);
+                            let r_2 = Ok(a_2);
+
+                            Ok(RunResult::Multi(
+                                RunSingleResult {
+                                    result: r_1.map(Answer::p1),
+                                    time: t_1,
+                                    alloc: m_1,
+                                },
+                                RunSingleResult {
+                                    result: r_2.map(Answer::p2),
+                                    time: t_2,
+                                    alloc: m_2,
+                                },
+                            ))
+                        }
+                        (r_1, r_2) => Ok(RunResult::Multi(
+                            RunSingleResult {
+                                result: r_1.map(Answer::p1),
+                                time: t_1,
+                                alloc: m_1,
+                            },
+                            RunSingleResult {
+                                result: r_2.map(Answer::p2),
+                                time: t_2,
+                                alloc: m_2,
+                            },
+                        )),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[inline]
+pub fn time_fn<T>(f: impl FnOnce() -> T) -> (T, Duration, Option<AllocStats>) {
+    let ((result, time), alloc) = alloc::measure(|| {
+        let now = Instant::now();
+        let result = f();
+        (result, now.elapsed())
+    });
+    (result, time, alloc)
+}
+
+pub fn alloc_suffix(alloc: Option<AllocStats>) -> String {
+    alloc.map(|a| format!(" ({a})")).unwrap_or_default()
+}
+
+pub enum RunResult {
+    Single(RunSingleResult),
+    Multi(RunSingleResult, RunSingleResult),
+}
+
+impl RunResult {
+    pub fn answer(&self) -> Option<Answer> {
+        match self {
+            RunResult::Single(r) => r.result.as_ref().ok().cloned(),
+            RunResult::Multi(r_1, r_2) => {
+                let part = |r: &RunSingleResult| r.result.as_ref().ok().cloned();
+                let (a_1, a_2) = (part(r_1), part(r_2));
+                Answer::from_parts(
+                    a_1.as_ref().and_then(Answer::part_1).cloned(),
+                    a_2.as_ref().and_then(Answer::part_2).cloned(),
+                )
+            }
+        }
+    }
+}
+
+impl Display for RunResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunResult::Single(r) => {
+                write!(f, "{}", r)
+            }
+            RunResult::Multi(r_1, r_2) => {
+                write!(f, "Result:\n  {}\n  {}", r_1, r_2)
+            }
+        }
+    }
+}
+
+pub struct RunSingleResult {
+    pub result: day::Result,
+    pub time: Duration,
+    pub alloc: Option<AllocStats>,
+}
+
+impl Display for RunSingleResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.result {
+            Ok(a) if a.is_multiline() => write!(
+                f,
+                "Obtained result in {:.2?}{}:\n    {}",
+                self.time,
+                alloc_suffix(self.alloc),
+                a.indented(4)
+            ),
+            Ok(a) => write!(
+                f,
+                "Obtained result: {} in {:.2?}{}",
+                a,
+                self.time,
+                alloc_suffix(self.alloc)
+            ),
+            Err(e) => write!(f, "Failed: {}", e),
+        }
+    }
+}
+
+#[test]
+fn should_keep_placeholder_parts_through_json() {
+    // given
+    let result = DayResult::Separated([
+        PartResult {
+            answer: "42".into(),
+            time: Duration::from_millis(1),
+            alloc: None,
+        },
+        PartResult {
+            answer: AnswerValue::Unimplemented,
+            time: Duration::ZERO,
+            alloc: None,
+        },
+    ]);
+    let combined = DayResult::Combined {
+        answer: (AnswerValue::Unimplemented, "ab\ncd".into()),
+        time: Duration::from_millis(1),
+        alloc: None,
+    };
+    // when
+    let round_trip = |result: &DayResult| {
+        let json = serde_json::to_string(result).unwrap();
+        serde_json::from_str::<DayResult>(&json).unwrap().answer()
+    };
+    // then
+    assert_eq!(
+        Answer::both("42", AnswerValue::Unimplemented),
+        round_trip(&result)
+    );
+    assert_eq!(
+        Answer::both(AnswerValue::Unimplemented, "ab\ncd"),
+        round_trip(&combined)
+    );
+}
+
+#[test]
+fn should_take_median_times() {
+    // given
+    let part = |answer: &str, millis| PartResult {
+        answer: answer.into(),
+        time: Duration::from_millis(millis),
+        alloc: None,
+    };
+    let results = [(5, 1), (1, 9), (3, 2)]
+        .map(|(p_1, p_2)| DayResult::Separated([part("1", p_1), part("2", p_2)]))
+        .to_vec();
+    // when
+    let median = DayResult::median(results);
+    // then
+    let DayResult::Separated([p_1, p_2]) = median else {
+        panic!("expected separated parts")
+    };
+    assert_eq!(Duration::from_millis(3), p_1.time);
+    assert_eq!(Duration::from_millis(2), p_2.time);
+}
+
+#[test]
+fn should_only_check_budgets_of_days_that_ran() {
+    // given
+    let run = |cached| DayRun {
+        day: Day::new(1, 2024),
+        result: DayResult::Combined {
+            answer: ("1".into(), "2".into()),
+            time: Duration::from_millis(20),
+            alloc: None,
+        },
+        warnings: vec![],
+        spans: SpanReport::default(),
+        cached,
+    };
+    let budget = Some(Duration::from_millis(10));
+    // when
+    let ran = run(false).over_budget(budget);
+    let cached = run(true).over_budget(budget);
+    // then
+    assert_eq!(budget, ran);
+    assert_eq!(None, cached);
+}
+
+#[test]
+fn should_run_day_from_text() {
+    // given
+    let day = Day { day: 1, year: 2024 };
+    let source = RunSource::Single(SingleSource::Text(
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3".to_string(),
+    ));
+    // when
+    let result = run_day(day, None, source, &DaysMeta::default()).unwrap();
+    // then
+    assert_eq!(Some(Answer::both("11", "31")), result.answer());
+}
+
+#[test]
+fn should_generate_same_input_for_seed() {
+    // given
+    let day = Day {
+        day: 22,
+        year: 2024,
+    };
+    // when
+    let first = generate(day, 3, 10).unwrap();
+    let second = generate(day, 3, 10).unwrap();
+    // then
+    assert_eq!(first, second);
+    assert_eq!(10, first.lines().count());
+}
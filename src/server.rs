@@ -0,0 +1,487 @@
+use crate::cli::PartArgs;
+use crate::context::{Context, Input};
+use crate::crypt;
+use crate::day::{Answer, AnswerValue, Day, DaysMeta, Example, Solver, SolverDatabase, Verdict};
+use crate::profile;
+use crate::runner::{self, time_fn, RunError};
+use serde::{Deserialize, Deserializer};
+use serde_json::{json, Value};
+use std::any::Any;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufRead, Cursor, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// A request line, e.g. `{"id": 1, "cmd": "run", "day": "17-2024", "part": 1}`.
+///
+/// The `id` is echoed back so clients can match responses to requests.
+#[derive(Debug, Deserialize)]
+struct Envelope {
+    #[serde(default)]
+    id: Value,
+    #[serde(flatten)]
+    request: Request,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "cmd", rename_all = "snake_case")]
+enum Request {
+    /// Every day with a solver or meta entry
+    List,
+    Run {
+        #[serde(deserialize_with = "parse_day")]
+        day: Day,
+        part: Option<u8>,
+        #[serde(default)]
+        source: Source,
+        variant: Option<String>,
+    },
+    /// Checks the examples of one day, or of every day in the meta
+    Examples {
+        #[serde(default, deserialize_with = "parse_optional_day")]
+        day: Option<Day>,
+    },
+    Bench {
+        #[serde(deserialize_with = "parse_day")]
+        day: Day,
+        part: Option<u8>,
+        #[serde(default)]
+        source: Source,
+        variant: Option<String>,
+        #[serde(default = "default_seconds")]
+        seconds: f64,
+    },
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Source {
+    #[default]
+    Full,
+    Example,
+    File(PathBuf),
+    Text(String),
+}
+
+impl Source {
+    fn input(&self) -> Input {
+        match self {
+            Source::Full => Input::Full,
+            Source::Example => Input::Example,
+            Source::File(_) | Source::Text(_) => Input::Other,
+        }
+    }
+}
+
+fn default_seconds() -> f64 {
+    1.0
+}
+
+fn parse_day<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Day, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    s.parse()
+        .map_err(|_| serde::de::Error::custom(format!("expected DAY-YEAR, got {s:?}")))
+}
+
+fn parse_optional_day<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Day>, D::Error> {
+    parse_day(deserializer).map(Some)
+}
+
+/// Answers newline-delimited JSON requests, keeping inputs in memory between requests.
+pub struct Server<'a> {
+    meta: &'a DaysMeta,
+    /// File contents by path, re-read when the file is modified
+    cache: HashMap<PathBuf, (SystemTime, Arc<[u8]>)>,
+    /// The budget of days without their own in the meta
+    budget: Option<Duration>,
+}
+
+impl<'a> Server<'a> {
+    pub fn new(meta: &'a DaysMeta) -> Self {
+        Self {
+            meta,
+            cache: HashMap::new(),
+            budget: None,
+        }
+    }
+
+    pub fn with_budget(mut self, budget: Option<Duration>) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// Handles requests from `input` until it is closed, writing one response line per request.
+    pub fn serve(&mut self, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let response = self.handle_line(&line);
+            serde_json::to_writer(&mut output, &response)?;
+            writeln!(output)?;
+            output.flush()?;
+        }
+        Ok(())
+    }
+
+    fn handle_line(&mut self, line: &str) -> Value {
+        let (id, result) = match serde_json::from_str::<Envelope>(line) {
+            // a solver panicking on half-typed input shouldn't take the server down with it
+            Ok(Envelope { id, request }) => {
+                let result = panic::catch_unwind(AssertUnwindSafe(|| self.handle(request)));
+                (id, result.unwrap_or_else(|e| Err(panicked(e))))
+            }
+            Err(e) => (Value::Null, Err(e.into())),
+        };
+
+        match result {
+            Ok(Value::Object(mut body)) => {
+                body.insert("id".to_string(), id);
+                body.insert("ok".to_string(), true.into());
+                Value::Object(body)
+            }
+            Ok(body) => json!({ "id": id, "ok": true, "result": body }),
+            Err(e) => json!({ "id": id, "ok": false, "error": e.to_string() }),
+        }
+    }
+
+    fn handle(&mut self, request: Request) -> anyhow::Result<Value> {
+        match request {
+            Request::List => Ok(self.list()),
+            Request::Run {
+                day,
+                part,
+                source,
+                variant,
+            } => self.run(day, part, source, variant.as_deref()),
+            Request::Examples { day } => self.examples(day),
+            Request::Bench {
+                day,
+                part,
+                source,
+                variant,
+                seconds,
+            } => self.bench(day, part, source, variant.as_deref(), seconds),
+        }
+    }
+
+    fn list(&self) -> Value {
+        let database = SolverDatabase::global();
+        let mut days = database.days();
+        days.extend(self.meta.0.keys().filter_map(|k| k.parse::<Day>().ok()));
+        days.sort_unstable();
+        days.dedup();
+
+        let days = days
+            .into_iter()
+            .map(|day| {
+                let variants = database
+                    .variants(&day)
+                    .into_iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>();
+                json!({
+                    "day": day.to_string(),
+                    "meta": self.meta.get_day(day).is_some(),
+                    "variants": variants,
+                    "generator": database.get_generator(&day).is_some(),
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({ "days": days })
+    }
+
+    fn run(
+        &mut self,
+        day: Day,
+        part: Option<u8>,
+        source: Source,
+        variant: Option<&str>,
+    ) -> anyhow::Result<Value> {
+        let solver = runner::find_solver(day, variant, self.meta)?;
+        Context::enter(day);
+        Context::enter_input(source.input());
+
+        let parts = parts(part)?;
+        let inputs = parts
+            .iter()
+            .map(|part| self.input(day, part, &source))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        // solve both parts in one go when they share an input, so combined solvers only run once
+        let runs = match &inputs[..] {
+            [p_1, p_2] if p_1 == p_2 => vec![(PartArgs::Both, p_1.clone())],
+            _ => parts.into_iter().zip(inputs).collect(),
+        };
+
+        let (mut p_1, mut p_2, mut time) = (None, None, Duration::ZERO);
+        for (part, input) in runs {
+            let open = || Ok(Cursor::new(input.clone()));
+            let (answer, t, _) = time_fn(|| runner::solve_with(&solver, &part, open));
+            let answer = answer?;
+            p_1 = p_1.or(answer.part_1().cloned());
+            p_2 = p_2.or(answer.part_2().cloned());
+            time += t;
+        }
+        let answer = Answer::from_parts(p_1, p_2).expect("at least one part is run");
+
+        Ok(json!({
+            "day": day.to_string(),
+            "answer": answer_json(&answer),
+            "time_ms": millis(time),
+        }))
+    }
+
+    fn examples(&mut self, day: Option<Day>) -> anyhow::Result<Value> {
+        let days = match day {
+            Some(day) => vec![day],
+            None => {
+                let mut days = self
+                    .meta
+                    .0
+                    .keys()
+                    .filter_map(|k| k.parse::<Day>().ok())
+                    .collect::<Vec<_>>();
+                days.sort_unstable();
+                days
+            }
+        };
+
+        let mut results = Vec::new();
+        for day in days {
+            let info = self.meta.get_day(day).ok_or(RunError::NoMeta(day))?;
+            let checks = match &info.example {
+                Example::Single {
+                    path,
+                    expected_answer,
+                } => vec![(path.clone(), expected_answer.clone())],
+                Example::Multi {
+                    path_1,
+                    expected_answer_1,
+                    path_2,
+                    expected_answer_2,
+                } => vec![
+                    (path_1.clone(), Answer::P1(expected_answer_1.clone())),
+                    (path_2.clone(), Answer::P2(expected_answer_2.clone())),
+                ],
+            };
+
+            for (path, expected) in checks {
+                let part = match expected {
+                    Answer::P1(_) => PartArgs::P1,
+                    Answer::P2(_) => PartArgs::P2,
+                    Answer::Both(..) => PartArgs::Both,
+                };
+                let input = self.file(&path)?;
+                let open = || Ok(Cursor::new(input.clone()));
+                let (actual, time, _) =
+                    time_fn(|| runner::solve(day, info, &part, Input::Example, open));
+                let mut result = json!({
+                    "day": day.to_string(),
+                    "expected": answer_json(&expected),
+                    "time_ms": millis(time),
+                });
+                match actual {
+                    Ok(actual) => {
+                        let verdict = actual.check(&expected);
+                        result["passed"] = (verdict == Verdict::Passed).into();
+                        result["actual"] = answer_json(&actual);
+                        if let Verdict::Unimplemented(level) = verdict {
+                            result["error"] =
+                                RunError::ExampleUnimplemented(level).to_string().into();
+                        }
+                    }
+                    Err(e) => {
+                        result["passed"] = false.into();
+                        result["error"] = e.to_string().into();
+                    }
+                }
+                results.push(result);
+            }
+        }
+
+        Ok(json!({ "examples": results }))
+    }
+
+    fn bench(
+        &mut self,
+        day: Day,
+        part: Option<u8>,
+        source: Source,
+        variant: Option<&str>,
+        seconds: f64,
+    ) -> anyhow::Result<Value> {
+        let duration = Duration::try_from_secs_f64(seconds)
+            .ok()
+            .filter(|d| !d.is_zero())
+            .ok_or_else(|| anyhow::anyhow!("seconds should be a positive number, got {seconds}"))?;
+        let solver = runner::find_solver(day, variant, self.meta)?;
+        Context::enter(day);
+        Context::enter_input(source.input());
+
+        // budgets are for both parts of the full input, so only a full bench is checked
+        let budget = match (&source, self.meta.get_day(day)) {
+            (Source::Full, Some(info)) => info.budget(self.budget),
+            (Source::Full, None) => self.budget,
+            _ => None,
+        };
+        let checked = budget.is_some() && part.is_none();
+
+        let mut results = Vec::new();
+        let mut median = Duration::ZERO;
+        for part in parts(part)? {
+            let input = self.input(day, &part, &source)?;
+            let stats = profile::run_loop(&solver, &part, input, duration)?;
+            // a combined solver runs both parts for each of them
+            median = match &*solver {
+                Solver::Combined(_) => median.max(stats.median),
+                Solver::Separated(_) => median + stats.median,
+            };
+            results.push(json!({
+                "part": part_number(&part),
+                "iterations": stats.iterations,
+                "mean_ms": millis(stats.total / stats.iterations.max(1)),
+                "median_ms": millis(stats.median),
+                "min_ms": millis(stats.min),
+            }));
+        }
+        profile::take_spans();
+
+        let mut response = json!({ "day": day.to_string(), "parts": results });
+        if let Some(budget) = budget.filter(|_| checked) {
+            response["budget_ms"] = json!(millis(budget));
+            response["over_budget"] = json!(median > budget);
+        }
+        Ok(response)
+    }
+
+    /// The input for `part` from `source`, from the cache if the file hasn't changed.
+    fn input(&mut self, day: Day, part: &PartArgs, source: &Source) -> anyhow::Result<Arc<[u8]>> {
+        let info = || self.meta.get_day(day).ok_or(RunError::NoMeta(day));
+        let path = match source {
+            Source::Text(text) => return Ok(text.as_bytes().into()),
+            Source::File(path) => path.clone(),
+            Source::Full => info()?.full.clone(),
+            Source::Example => match &info()?.example {
+                Example::Single { path, .. } => path.clone(),
+                Example::Multi { path_2, .. } if *part == PartArgs::P2 => path_2.clone(),
+                Example::Multi { path_1, .. } => path_1.clone(),
+            },
+        };
+        Ok(self.file(&path)?)
+    }
+
+    fn file(&mut self, path: &Path) -> io::Result<Arc<[u8]>> {
+        let modified = fs::metadata(crypt::stored_path(path))?.modified()?;
+        if let Some((cached, contents)) = self.cache.get(path) {
+            if *cached == modified {
+                return Ok(contents.clone());
+            }
+        }
+
+        let contents: Arc<[u8]> = crypt::read(path)?.into();
+        self.cache
+            .insert(path.to_path_buf(), (modified, contents.clone()));
+        Ok(contents)
+    }
+}
+
+fn parts(part: Option<u8>) -> anyhow::Result<Vec<PartArgs>> {
+    match part {
+        None => Ok(vec![PartArgs::P1, PartArgs::P2]),
+        Some(1) => Ok(vec![PartArgs::P1]),
+        Some(2) => Ok(vec![PartArgs::P2]),
+        Some(n) => Err(anyhow::anyhow!("no part {n}")),
+    }
+}
+
+fn part_number(part: &PartArgs) -> Option<u8> {
+    match part {
+        PartArgs::P1 => Some(1),
+        PartArgs::P2 => Some(2),
+        PartArgs::Both => None,
+    }
+}
+
+/// The answer as `{"part_1": ..., "part_2": ...}`, with `null` for parts that weren't run and
+/// `{"implemented": false}` for placeholder parts.
+pub fn answer_json(answer: &Answer) -> Value {
+    let part = |a: Option<&AnswerValue>| match a {
+        Some(AnswerValue::Unimplemented) => json!({ "implemented": false }),
+        a => json!(a.map(AnswerValue::to_string)),
+    };
+    json!({ "part_1": part(answer.part_1()), "part_2": part(answer.part_2()) })
+}
+
+/// The error for a request whose solver panicked.
+fn panicked(payload: Box<dyn Any + Send>) -> anyhow::Error {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("no message");
+    anyhow::anyhow!("the solver panicked: {message}")
+}
+
+fn millis(time: Duration) -> f64 {
+    time.as_secs_f64() * 1000.0
+}
+
+#[test]
+fn should_answer_requests() {
+    // given
+    let meta = DaysMeta::default();
+    let mut server = Server::new(&meta);
+    let input = concat!(
+        r#"{"id": 1, "cmd": "run", "day": "1-2024", "source": {"text": "3   4\n4   3\n2   5\n1   3\n3   9\n3   3"}}"#,
+        "\n",
+        r#"{"id": 2, "cmd": "run", "day": "1-2024"}"#,
+        "\n",
+        "not json\n",
+        r#"{"id": 4, "cmd": "run", "day": "1-2024", "source": {"text": "abc"}}"#,
+        "\n",
+        r#"{"id": 5, "cmd": "bench", "day": "1-2024", "seconds": -1}"#,
+        "\n",
+    );
+    let mut output = Vec::new();
+    // when
+    server.serve(input.as_bytes(), &mut output).unwrap();
+    // then
+    let responses = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|l| serde_json::from_str::<Value>(l).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(5, responses.len());
+    assert_eq!(json!(1), responses[0]["id"]);
+    assert_eq!(
+        json!({"part_1": "11", "part_2": "31"}),
+        responses[0]["answer"]
+    );
+    assert_eq!(json!(false), responses[1]["ok"]);
+    assert_eq!("no meta for 1-2024", responses[1]["error"]);
+    assert_eq!(json!(false), responses[2]["ok"]);
+    assert_eq!(json!(4), responses[3]["id"]);
+    assert!(responses[3]["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("the solver panicked"));
+    assert_eq!(
+        "seconds should be a positive number, got -1",
+        responses[4]["error"]
+    );
+}
+
+#[test]
+fn should_mark_placeholder_parts() {
+    // when
+    let json = answer_json(&Answer::both("3", AnswerValue::Unimplemented));
+    // then
+    assert_eq!(
+        json!({"part_1": "3", "part_2": {"implemented": false}}),
+        json
+    );
+}
@@ -100,7 +100,7 @@ impl Number {
             Number::Num(i) => {
                 if *i >= 10 {
                     let left = *i / 2;
-                    let right = (*i + 1) / 2;
+                    let right = (*i).div_ceil(2);
 
                     *self = Self::Pair(Self::Num(left).into(), Self::Num(right).into());
 
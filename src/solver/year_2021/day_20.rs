@@ -8,22 +8,34 @@ use crate::day::CombinedSolver;
 
 pub struct Day20;
 
+/// Only handles algorithms that leave the infinite background dark
+pub struct Simple;
+
+/// Steps twice at a time so the background can flash on and back off
+pub struct Complex;
+
+fn parse(input: Box<dyn BufRead>) -> (Vec<bool>, Map) {
+    let mut lines = input.lines().map(Result::unwrap);
+    let line = lines.next().unwrap();
+    let algorithm = line.chars().map(|c| c == '#').collect_vec();
+    lines.next(); // ignore empty line
+    let map: Map = lines
+        .enumerate()
+        .flat_map(|(y, s)| {
+            s.chars()
+                .enumerate()
+                .filter(|(_, c)| *c == '#')
+                .map(|(x, _)| IVec2::new(x as i32, y as i32))
+                .collect_vec()
+        })
+        .collect();
+
+    (algorithm, map)
+}
+
 impl CombinedSolver for Day20 {
     fn solve(&self, input: Box<dyn BufRead>) -> anyhow::Result<(String, String)> {
-        let mut lines = input.lines().map(Result::unwrap);
-        let line = lines.next().unwrap();
-        let algorithm = line.chars().map(|c| c == '#').collect_vec();
-        lines.next(); // ignore empty line
-        let map: Map = lines
-            .enumerate()
-            .flat_map(|(y, s)| {
-                s.chars()
-                    .enumerate()
-                    .filter(|(_, c)| *c == '#')
-                    .map(|(x, _)| IVec2::new(x as i32, y as i32))
-                    .collect_vec()
-            })
-            .collect();
+        let (algorithm, map) = parse(input);
 
         let part_1 = if !algorithm[0] {
             simple_solver(&algorithm, map.clone(), 2)
@@ -41,6 +53,26 @@ impl CombinedSolver for Day20 {
     }
 }
 
+impl CombinedSolver for Simple {
+    fn solve(&self, input: Box<dyn BufRead>) -> anyhow::Result<(String, String)> {
+        let (algorithm, map) = parse(input);
+        let part_1 = simple_solver(&algorithm, map.clone(), 2);
+        let part_2 = simple_solver(&algorithm, map, 50);
+
+        Ok((part_1.to_string(), part_2.to_string()))
+    }
+}
+
+impl CombinedSolver for Complex {
+    fn solve(&self, input: Box<dyn BufRead>) -> anyhow::Result<(String, String)> {
+        let (algorithm, map) = parse(input);
+        let part_1 = complex_solver(&algorithm, map.clone(), 1);
+        let part_2 = complex_solver(&algorithm, map, 25);
+
+        Ok((part_1.to_string(), part_2.to_string()))
+    }
+}
+
 type Map = HashSet<IVec2>;
 
 fn simple_solver(algorithm: &[bool], mut map: Map, n: usize) -> usize {
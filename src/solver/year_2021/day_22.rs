@@ -1,5 +1,8 @@
 use crate::day::CombinedSolver;
+use crate::profile;
 use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
 use std::io::BufRead;
 use std::str::FromStr;
 use utils::ranges::RangeD;
@@ -8,12 +11,15 @@ pub struct Day22;
 
 impl CombinedSolver for Day22 {
     fn solve(&self, input: Box<dyn BufRead>) -> anyhow::Result<(String, String)> {
+        let span = profile::span("parse");
         let steps = input
             .lines()
             .map(Result::unwrap)
             .map(|s| s.parse::<Step>().unwrap())
             .collect_vec();
+        drop(span);
 
+        let span = profile::span("part 1");
         let part_1_volume = steps
             .iter()
             .filter(|s| s.1.start.iter().all(|s| *s >= -50) && s.1.end.iter().all(|e| *e <= 51))
@@ -21,6 +27,9 @@ impl CombinedSolver for Day22 {
 
         let part_1 = part_1_volume.into_iter().map(|s| s.volume()).sum::<i64>();
 
+        drop(span);
+
+        let _span = profile::span("part 2");
         let part_2_volume = steps.iter().fold(State::new(), |acc, s| s.apply(acc));
 
         let part_2 = part_2_volume.into_iter().map(|s| s.volume()).sum::<i64>();
@@ -82,3 +91,33 @@ impl FromStr for Step {
         Ok(Self(state, RangeD::from_range_1d(ranges)))
     }
 }
+
+/// `size` reboot steps, starting with cuboids in the initialization region and then larger ones
+/// the same way the real inputs do.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut out = String::new();
+    for i in 0..size {
+        let (bound, max_len) = if i < size / 2 {
+            (50, 40)
+        } else {
+            (100_000, 50_000)
+        };
+        let state = if i == 0 || rng.gen_bool(0.6) {
+            "on"
+        } else {
+            "off"
+        };
+
+        let mut axis = || {
+            let len = rng.gen_range(1..=max_len);
+            let min = rng.gen_range(-bound..=bound - len);
+            (min, min + len)
+        };
+        let (x, y, z) = (axis(), axis(), axis());
+        out.push_str(&format!(
+            "{state} x={}..{},y={}..{},z={}..{}\n",
+            x.0, x.1, y.0, y.1, z.0, z.1
+        ));
+    }
+    out
+}
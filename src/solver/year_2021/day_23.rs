@@ -69,6 +69,15 @@ impl CombinedSolver for Day23 {
 
         Ok((part_1.to_string(), part_2.to_string()))
     }
+
+    // the search doesn't work yet
+    fn has_part_1(&self) -> bool {
+        false
+    }
+
+    fn has_part_2(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -498,7 +507,7 @@ impl State {
                         pos.neighbors(self.max_depth)
                             .into_iter()
                             // .map(|(p, c)| (p, c * amp.1.energy()))
-                            .filter(|(p, _)| next_state.map.get(p).is_none())
+                            .filter(|(p, _)| !next_state.map.contains_key(p))
                             .filter(|&(p, _)| {
                                 match p {
                                     // can move inside if still inside initial
@@ -531,7 +540,7 @@ impl State {
                             pos.neighbors(self.max_depth)
                                 .into_iter()
                                 // .map(|(p, c)| (p, c * amp.1.energy()))
-                                .filter(|(p, _)| next_state.map.get(p).is_none())
+                                .filter(|(p, _)| !next_state.map.contains_key(p))
                                 .filter(|&(p, _)| match p {
                                     PosNew::I(ip) => ip.amp == amp,
                                     PosNew::O(_) => true,
@@ -1,3 +1,4 @@
+use crate::context::{Context, Progress};
 use crate::day::CombinedSolver;
 use itertools::Itertools;
 use smallvec::SmallVec;
@@ -18,12 +19,16 @@ impl CombinedSolver for Day24 {
             .unwrap();
 
         let mut memo = Failed::new();
+        let mut progress = Context::current()
+            .progress("prefixes")
+            .with_total(9u64.pow(PROGRESS_DIGITS as u32));
         let part_1 = solve(
             Memory::default(),
             0,
             ModelNumber::new(),
             &instructions,
             &mut memo,
+            &mut progress,
         )
         .unwrap();
         let part_1 = part_1.into_iter().join("");
@@ -218,12 +223,17 @@ type ModelNumber = SmallVec<[u8; 14]>;
 
 type Failed = HashSet<(Memory, usize)>;
 
+/// Progress is counted in exhausted prefixes of this many digits.
+const PROGRESS_DIGITS: usize = 4;
+
+#[allow(clippy::only_used_in_recursion)]
 fn solve(
     mut state: Memory,
     mut i: usize,
     cur: ModelNumber,
     instructions: &[Instruction],
     failed: &mut Failed,
+    progress: &mut Progress,
 ) -> Option<ModelNumber> {
     // unsafe { total += 1; }
     // if failed.contains(&(state, i)) {
@@ -248,7 +258,9 @@ fn solve(
                             let mut model = cur.clone();
                             model.push(n);
 
-                            if let Some(sol) = solve(state, i + 1, model, instructions, failed) {
+                            if let Some(sol) =
+                                solve(state, i + 1, model, instructions, failed, progress)
+                            {
                                 return Some(sol);
                             } else {
                                 // failed.insert((state, i));
@@ -256,7 +268,9 @@ fn solve(
                         }
 
                         // print!("{} {}: ", failed.len(), unsafe { total - hit });
-                        println!("{} - {} @{}", cur.iter().copied().join(""), state, i);
+                        if cur.len() == PROGRESS_DIGITS {
+                            progress.inc(1);
+                        }
                         // failed.insert((state, i));
                         return None;
                     }
@@ -215,8 +215,8 @@ fn step(to_move: &mut Movers, candidates: &mut Movers, grid: &mut Grid<Cell>) {
     }
 
     for p in to_move.south.drain() {
-        let n = Dir::South.step(p, &grid);
-        let b = Dir::South.unstep(p, &grid);
+        let n = Dir::South.step(p, grid);
+        let b = Dir::South.unstep(p, grid);
         if grid.get(b.0, b.1).unwrap().0.is_some_and(|x| x.is_south()) {
             candidates.south.insert(b);
         }
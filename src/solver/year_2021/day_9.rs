@@ -49,7 +49,7 @@ impl CombinedSolver for Day9 {
             for x in bfs_reach(start, |&p| {
                 successors(p, &grid)
                     .into_iter()
-                    .filter(|&(x, y)| !grid.get(x, y).is_some_and(|n| *n == 9))
+                    .filter(|&(x, y)| grid.get(x, y).is_none_or(|n| *n != 9))
             }) {
                 to_visit.swap_remove(&x);
                 count += 1;
@@ -106,4 +106,17 @@ pub fn add_all(solver_database: &mut SolverDatabase) {
     for (day, solver) in solvers {
         solver_database.add_solver(day, solver);
     }
+
+    solver_database.add_variant(
+        Day::new(20, 2021),
+        "simple",
+        Solver::combined(day_20::Simple),
+    );
+    solver_database.add_variant(
+        Day::new(20, 2021),
+        "complex",
+        Solver::combined(day_20::Complex),
+    );
+
+    solver_database.add_generator(Day::new(22, 2021), day_22::generate);
 }
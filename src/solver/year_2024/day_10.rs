@@ -6,8 +6,8 @@ use std::fmt::Display;
 use std::io::BufRead;
 use utils::grid::Grid;
 
-pub fn solve(input: Box<dyn BufRead>) -> anyhow::Result<(impl Display, impl Display)> {
-    let grid: Grid<u8> = input
+fn parse(input: Box<dyn BufRead>) -> Grid<u8> {
+    input
         .lines()
         .map(|l| {
             l.unwrap()
@@ -15,7 +15,11 @@ pub fn solve(input: Box<dyn BufRead>) -> anyhow::Result<(impl Display, impl Disp
                 .map(|c| c.to_digit(10).unwrap() as u8)
                 .collect_vec()
         })
-        .collect();
+        .collect()
+}
+
+pub fn solve(input: Box<dyn BufRead>) -> anyhow::Result<(impl Display, impl Display)> {
+    let grid = parse(input);
 
     let mut p_1 = 0;
     let mut p_2 = 0;
@@ -26,8 +30,6 @@ pub fn solve(input: Box<dyn BufRead>) -> anyhow::Result<(impl Display, impl Disp
             continue;
         }
 
-        // p_1 += find_1(IVec2::new(x as i32, y as i32), &grid);
-
         p_1 += find(IVec2::new(x as i32, y as i32), &grid, &mut memo_1).len();
         p_2 += find_2(IVec2::new(x as i32, y as i32), &grid, &mut memo_2);
     }
@@ -35,6 +37,25 @@ pub fn solve(input: Box<dyn BufRead>) -> anyhow::Result<(impl Display, impl Disp
     Ok((p_1, p_2))
 }
 
+/// Same as [`solve`], but searches from every trailhead separately for part 1.
+pub fn solve_search(input: Box<dyn BufRead>) -> anyhow::Result<(impl Display, impl Display)> {
+    let grid = parse(input);
+
+    let mut p_1 = 0;
+    let mut p_2 = 0;
+    let mut memo_2 = HashMap::new();
+    for ((x, y), &v) in grid.iter() {
+        if v != 0 {
+            continue;
+        }
+
+        p_1 += find_1(IVec2::new(x as i32, y as i32), &grid);
+        p_2 += find_2(IVec2::new(x as i32, y as i32), &grid, &mut memo_2);
+    }
+
+    Ok((p_1, p_2))
+}
+
 type Storage = SmallSet<[IVec2; 6]>;
 
 fn find(pos: IVec2, grid: &Grid<u8>, memo: &mut HashMap<IVec2, Storage>) -> Storage {
@@ -101,7 +122,7 @@ fn find_2(pos: IVec2, grid: &Grid<u8>, memo: &mut HashMap<IVec2, usize>) -> usiz
 
 // intended solution
 // only slightly faster because the other one can be shared between runs
-fn _find_1(pos: IVec2, grid: &Grid<u8>) -> usize {
+fn find_1(pos: IVec2, grid: &Grid<u8>) -> usize {
     let mut count = 0;
     let mut visited = HashSet::new();
 
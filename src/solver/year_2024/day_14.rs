@@ -1,9 +1,10 @@
+use crate::visualize::Frame;
 use hashbrown::HashSet;
-use itertools::Itertools;
 use regex::Regex;
 use std::cmp::Ordering;
 use std::fmt::Display;
-use std::io::{stdin, BufRead};
+use std::io::BufRead;
+use utils::grid::Grid;
 
 const WIDTH: i32 = 101;
 const HEIGHT: i32 = 103;
@@ -44,54 +45,86 @@ pub fn part_1(input: Box<dyn BufRead>) -> anyhow::Result<impl Display> {
     Ok(p_1)
 }
 
-const S: &str = include_str!("../../../data/2024/full-14-24.txt");
+/// The first time no two robots share a tile, which is when they draw the Christmas tree.
+pub fn part_2(input: Box<dyn BufRead>) -> anyhow::Result<impl Display> {
+    let robots = parse(input)?;
+    (0..WIDTH * HEIGHT)
+        .find(|&n| without_overlap(&robots, n).is_some())
+        .ok_or_else(|| anyhow::anyhow!("the robots always overlap"))
+}
 
-pub fn part_2() {
-    let regex = Regex::new(r#"p=(\d+),(\d+) v=(-?\d+),(-?\d+)"#).unwrap();
-    let r = S
-        .lines()
-        .map(|line| {
-            let capture = regex.captures(&line).unwrap();
-            [1, 2, 3, 4].map(|i| capture[i].parse::<i32>().unwrap())
-        })
-        .collect_vec();
+/// Every time the robots bunch up in the same columns or rows, until they do both at once and
+/// draw the tree.
+pub fn visualize(input: Box<dyn BufRead>) -> anyhow::Result<impl Iterator<Item = Frame>> {
+    let robots = parse(input)?;
+    // columns repeat every WIDTH seconds and rows every HEIGHT seconds
+    let x_0 = tightest(&robots, WIDTH, |[p_x, _, v_x, _], n| {
+        (p_x + v_x * n).rem_euclid(WIDTH)
+    });
+    let y_0 = tightest(&robots, HEIGHT, |[_, p_y, _, v_y], n| {
+        (p_y + v_y * n).rem_euclid(HEIGHT)
+    });
+    let tree = (0..WIDTH * HEIGHT)
+        .find(|n| n % WIDTH == x_0 && n % HEIGHT == y_0)
+        .unwrap_or(0);
 
-    'outer: for n in 0.. {
-        let mut robots = HashSet::new();
-        for xy in r.iter().copied().map(|[p_x, p_y, v_x, v_y]| {
-            (
-                (p_x + v_x * n).rem_euclid(WIDTH) as usize,
-                (p_y + v_y * n).rem_euclid(HEIGHT) as usize,
-            )
-        }) {
-            if !robots.insert(xy) {
-                continue 'outer;
+    Ok((0..=tree)
+        .filter(move |n| n % WIDTH == x_0 || n % HEIGHT == y_0)
+        .map(move |n| {
+            let mut grid = Grid::new(vec![vec!['.'; WIDTH as usize]; HEIGHT as usize]);
+            for &[p_x, p_y, v_x, v_y] in &robots {
+                let (x, y) = (
+                    (p_x + v_x * n).rem_euclid(WIDTH),
+                    (p_y + v_y * n).rem_euclid(HEIGHT),
+                );
+                grid.grid[y as usize][x as usize] = '#';
             }
-        }
+            let caption = match (n % WIDTH == x_0, n % HEIGHT == y_0) {
+                (true, true) => format!("after {n} seconds: the tree"),
+                (true, false) => format!("after {n} seconds: columns line up"),
+                _ => format!("after {n} seconds: rows line up"),
+            };
+            Frame::new(grid, caption)
+        }))
+}
 
-        // let robots = r.iter().copied()
-        //     .map(|[p_x, p_y, v_x, v_y]| {
-        //         ((p_x + v_x * n).rem_euclid(WIDTH) as usize, (p_y + v_y * n).rem_euclid(HEIGHT) as usize)
-        //     }).collect::<HashSet<(usize, usize)>>();
+/// The time within one `period` at which the coordinate from `at` varies the least.
+fn tightest(robots: &[[i32; 4]], period: i32, at: impl Fn([i32; 4], i32) -> i32) -> i32 {
+    let variance = |n: i32| {
+        let values = robots.iter().map(|&r| at(r, n) as f64);
+        let mean = values.clone().sum::<f64>() / robots.len() as f64;
+        values.map(|v| (v - mean).powi(2)).sum::<f64>()
+    };
+    (0..period)
+        .min_by(|&a, &b| variance(a).total_cmp(&variance(b)))
+        .unwrap_or(0)
+}
 
-        // if robots.len() != r.len() {
-        //     continue 'outer;
-        // }
+fn parse(input: Box<dyn BufRead>) -> anyhow::Result<Vec<[i32; 4]>> {
+    let regex = Regex::new(r#"p=(\d+),(\d+) v=(-?\d+),(-?\d+)"#)?;
+    input
+        .lines()
+        .map(|line| {
+            let line = line?;
+            let capture = regex
+                .captures(&line)
+                .ok_or_else(|| anyhow::anyhow!("invalid robot {line:?}"))?;
+            Ok([1, 2, 3, 4].map(|i| capture[i].parse::<i32>().unwrap()))
+        })
+        .collect()
+}
 
-        for y in 0..HEIGHT as usize {
-            for x in 0..WIDTH as usize {
-                if robots.contains(&(x, y)) {
-                    print!("X");
-                } else {
-                    print!(".");
-                }
-            }
-            println!();
+/// The positions of the robots after `n` seconds, if no two are on the same tile.
+fn without_overlap(robots: &[[i32; 4]], n: i32) -> Option<HashSet<(usize, usize)>> {
+    let mut positions = HashSet::with_capacity(robots.len());
+    for &[p_x, p_y, v_x, v_y] in robots {
+        let xy = (
+            (p_x + v_x * n).rem_euclid(WIDTH) as usize,
+            (p_y + v_y * n).rem_euclid(HEIGHT) as usize,
+        );
+        if !positions.insert(xy) {
+            return None;
         }
-        println!("{n}");
-        let mut wait = String::new();
-        stdin().read_line(&mut wait).unwrap();
-        println!();
-        println!();
     }
+    Some(positions)
 }
@@ -92,7 +92,7 @@ impl Tile2 {
         match (self, dir) {
             (Tile2::Wall, _) => {}
             (Tile2::RightBox, Direction::West) => {
-                if let Some(t) = grid.get_i32(p.x - 2, p.y).unwrap().clone() {
+                if let Some(t) = *grid.get_i32(p.x - 2, p.y).unwrap() {
                     t.mov(IVec2::new(p.x - 2, p.y), grid, dir);
                 }
                 *grid.get_i32_mut(p.x - 2, p.y).unwrap() = Some(Tile2::LeftBox);
@@ -100,7 +100,7 @@ impl Tile2 {
                 *grid.get_i32_mut(p.x, p.y).unwrap() = None;
             }
             (Tile2::LeftBox, Direction::East) => {
-                if let Some(t) = grid.get_i32(p.x + 2, p.y).unwrap().clone() {
+                if let Some(t) = *grid.get_i32(p.x + 2, p.y).unwrap() {
                     t.mov(IVec2::new(p.x + 2, p.y), grid, dir);
                 }
                 *grid.get_i32_mut(p.x + 2, p.y).unwrap() = Some(Tile2::RightBox);
@@ -108,10 +108,10 @@ impl Tile2 {
                 *grid.get_i32_mut(p.x, p.y).unwrap() = None;
             }
             (Tile2::LeftBox, Direction::North) => {
-                if let Some(t) = grid.get_i32(p.x, p.y - 1).unwrap().clone() {
+                if let Some(t) = *grid.get_i32(p.x, p.y - 1).unwrap() {
                     t.mov(IVec2::new(p.x, p.y - 1), grid, dir);
                 }
-                if let Some(t) = grid.get_i32(p.x + 1, p.y - 1).unwrap().clone() {
+                if let Some(t) = *grid.get_i32(p.x + 1, p.y - 1).unwrap() {
                     t.mov(IVec2::new(p.x + 1, p.y - 1), grid, dir);
                 }
                 *grid.get_i32_mut(p.x, p.y - 1).unwrap() = Some(Tile2::LeftBox);
@@ -120,10 +120,10 @@ impl Tile2 {
                 *grid.get_i32_mut(p.x + 1, p.y).unwrap() = None;
             }
             (Tile2::RightBox, Direction::North) => {
-                if let Some(t) = grid.get_i32(p.x, p.y - 1).unwrap().clone() {
+                if let Some(t) = *grid.get_i32(p.x, p.y - 1).unwrap() {
                     t.mov(IVec2::new(p.x, p.y - 1), grid, dir);
                 }
-                if let Some(t) = grid.get_i32(p.x - 1, p.y - 1).unwrap().clone() {
+                if let Some(t) = *grid.get_i32(p.x - 1, p.y - 1).unwrap() {
                     t.mov(IVec2::new(p.x - 1, p.y - 1), grid, dir);
                 }
                 *grid.get_i32_mut(p.x, p.y - 1).unwrap() = Some(Tile2::RightBox);
@@ -132,10 +132,10 @@ impl Tile2 {
                 *grid.get_i32_mut(p.x - 1, p.y).unwrap() = None;
             }
             (Tile2::LeftBox, Direction::South) => {
-                if let Some(t) = grid.get_i32(p.x, p.y + 1).unwrap().clone() {
+                if let Some(t) = *grid.get_i32(p.x, p.y + 1).unwrap() {
                     t.mov(IVec2::new(p.x, p.y + 1), grid, dir);
                 }
-                if let Some(t) = grid.get_i32(p.x + 1, p.y + 1).unwrap().clone() {
+                if let Some(t) = *grid.get_i32(p.x + 1, p.y + 1).unwrap() {
                     t.mov(IVec2::new(p.x + 1, p.y + 1), grid, dir);
                 }
                 *grid.get_i32_mut(p.x, p.y + 1).unwrap() = Some(Tile2::LeftBox);
@@ -144,10 +144,10 @@ impl Tile2 {
                 *grid.get_i32_mut(p.x + 1, p.y).unwrap() = None;
             }
             (Tile2::RightBox, Direction::South) => {
-                if let Some(t) = grid.get_i32(p.x, p.y + 1).unwrap().clone() {
+                if let Some(t) = *grid.get_i32(p.x, p.y + 1).unwrap() {
                     t.mov(IVec2::new(p.x, p.y + 1), grid, dir);
                 }
-                if let Some(t) = grid.get_i32(p.x - 1, p.y + 1).unwrap().clone() {
+                if let Some(t) = *grid.get_i32(p.x - 1, p.y + 1).unwrap() {
                     t.mov(IVec2::new(p.x - 1, p.y + 1), grid, dir);
                 }
                 *grid.get_i32_mut(p.x, p.y + 1).unwrap() = Some(Tile2::RightBox);
@@ -257,7 +257,7 @@ pub fn part_1(input: Box<dyn BufRead>) -> anyhow::Result<(impl Display, impl Dis
             let dir = m.y_down();
             let new_p = robot + dir;
 
-            if match grid.get_i32(new_p.x, new_p.y).unwrap().clone() {
+            if match *grid.get_i32(new_p.x, new_p.y).unwrap() {
                 None => true,
                 Some(t) => {
                     if t.can_move(new_p, &grid, m) {
@@ -1,8 +1,8 @@
+use crate::context::{debug, Context};
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 use std::fmt::Display;
 use std::io::BufRead;
-use std::mem::swap;
 use std::ops::Range;
 
 fn parse(input: Box<dyn BufRead>) -> anyhow::Result<([u64; 3], Vec<u64>)> {
@@ -45,17 +45,18 @@ pub fn part_2(input: Box<dyn BufRead>) -> anyhow::Result<impl Display> {
     let mut failed_memo = HashSet::new();
     let mut failed_as = HashMap::new();
     let start = if program.len() == 6 { 0 } else { 1 << 48 };
+    let mut progress = Context::current().progress("a");
     let p_2 = (start..)
-        .filter(|&a| {
+        .find(|&a| {
             if failed_as.len() > 10_000_000 {
                 failed_as.clear();
             }
             if a % 100000 == 0 {
-                println!("{a}: {}", failed_as.len());
+                progress.set(a - start);
             }
             run_recurse(
                 State {
-                    a: a,
+                    a,
                     b,
                     c,
                     pointer: 0,
@@ -66,9 +67,9 @@ pub fn part_2(input: Box<dyn BufRead>) -> anyhow::Result<impl Display> {
                 &mut failed_as,
             )
         })
-        .next()
         .unwrap();
-    Ok(dbg!(p_2))
+    debug!("p_2 = {p_2}");
+    Ok(p_2)
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -99,9 +100,7 @@ impl From<State> for StateNoA {
     }
 }
 
-static mut hit: usize = 0;
-static mut total: usize = 0;
-
+#[allow(clippy::only_used_in_recursion)]
 fn run_recurse(
     state: State,
     program: &[u64],
@@ -229,7 +228,7 @@ fn run_recurse(
     // }
 }
 
-fn run(mut a: u64, mut b: u64, mut c: u64, program: &[u64]) -> Vec<u64> {
+fn run(a: u64, b: u64, c: u64, program: &[u64]) -> Vec<u64> {
     // println!("{a}");
     let mut output = Vec::new();
     let (mut a, mut b, mut c) = (a, b, c);
@@ -311,8 +310,19 @@ c = ...000
 
  */
 
-#[test]
-fn part_2_hope() {
+/// The program these bit patterns were worked out by hand for.
+const HOPE_PROGRAM: [u64; 16] = [2, 4, 1, 7, 7, 5, 4, 1, 1, 4, 5, 5, 0, 3, 3, 0];
+
+/// Builds `a` from the bits each output value constrains, instead of searching for it.
+///
+/// Only works for [`HOPE_PROGRAM`].
+pub fn part_2_hope(input: Box<dyn BufRead>) -> anyhow::Result<impl Display> {
+    let (_, program) = parse(input)?;
+    anyhow::ensure!(
+        program == HOPE_PROGRAM,
+        "bit patterns were only worked out for {HOPE_PROGRAM:?}"
+    );
+
     let mut map = HashMap::new();
     const O: Option<bool> = Some(false);
     const I: Option<bool> = Some(true);
@@ -394,11 +404,10 @@ fn part_2_hope() {
         ],
     );
 
-    let sequence = vec![0, 3, 3, 0, 5, 5, 4, 1, 1, 4, 5, 7, 7, 1, 4, 2];
-    let mut sequence = sequence.into_iter();
+    let mut sequence = program.into_iter().rev();
     let mut current = map.get(&sequence.next().unwrap()).unwrap().clone();
     // let mut next = Vec::new();
-    while let Some(i) = sequence.next() {
+    for i in sequence {
         let is = map.get(&i).unwrap();
         current = current
             .into_iter()
@@ -410,8 +419,8 @@ fn part_2_hope() {
             .collect_vec();
     }
 
-    let p_2 = current.into_iter().map(|s| to_min_u128(s)).min().unwrap();
-    dbg!(p_2);
+    let p_2 = current.into_iter().map(to_min_u128).min().unwrap();
+    Ok(p_2)
 }
 
 fn merge(mut a: Vec<Option<bool>>, mut b: Vec<Option<bool>>) -> Option<Vec<Option<bool>>> {
@@ -419,16 +428,16 @@ fn merge(mut a: Vec<Option<bool>>, mut b: Vec<Option<bool>>) -> Option<Vec<Optio
     let y = b.pop().unwrap();
     let x = b.pop().unwrap();
     if b.len() > a.len() {
-        swap(&mut a, &mut b);
+        std::mem::swap(&mut a, &mut b);
     }
 
     let s = a.len() - b.len();
     let mut b = b.into_iter();
 
-    for i in s..a.len() {
-        let x = a[i];
+    for slot in a.iter_mut().skip(s) {
+        let x = *slot;
         let y = b.next().unwrap();
-        a[i] = match (x, y) {
+        *slot = match (x, y) {
             (None, x) | (x, None) => x,
             (Some(true), Some(true)) => Some(true),
             (Some(false), Some(false)) => Some(false),
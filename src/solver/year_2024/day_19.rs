@@ -23,7 +23,7 @@ pub fn solve(input: Box<dyn BufRead>) -> anyhow::Result<(impl Display, impl Disp
 }
 
 fn can_make(design: &[Towel], towels: &[Vec<Towel>]) -> bool {
-    if design.len() == 0 {
+    if design.is_empty() {
         return true;
     }
 
@@ -32,10 +32,10 @@ fn can_make(design: &[Towel], towels: &[Vec<Towel>]) -> bool {
             continue;
         }
 
-        if design.iter().zip(towel.iter()).all(|(x, y)| x == y) {
-            if can_make(&design[towel.len()..], towels) {
-                return true;
-            }
+        if design.iter().zip(towel.iter()).all(|(x, y)| x == y)
+            && can_make(&design[towel.len()..], towels)
+        {
+            return true;
         }
     }
 
@@ -1,6 +1,6 @@
 use glam::IVec2;
 use itertools::Itertools;
-use pathfinding::prelude::{bfs, bfs_reach};
+use pathfinding::prelude::bfs;
 use std::collections::{HashSet, VecDeque};
 use std::fmt::Display;
 use std::io::BufRead;
@@ -1,10 +1,10 @@
+use crate::context::{debug, trace};
 use glam::IVec2;
-use itertools::{Either, Itertools};
+use itertools::Itertools;
 use memoize::memoize;
-use pathfinding::prelude::bfs;
 use smallvec::{smallvec, SmallVec};
 use std::cmp::Ordering;
-use std::fmt::{Display, Formatter};
+use std::fmt::Display;
 use std::io::BufRead;
 
 pub fn solution(input: Box<dyn BufRead>) -> anyhow::Result<(impl Display, impl Display)> {
@@ -18,7 +18,7 @@ pub fn solution(input: Box<dyn BufRead>) -> anyhow::Result<(impl Display, impl D
         .iter()
         .map(|s| s.strip_suffix("A").unwrap().parse::<u64>().unwrap() * solve(s, 25))
         .sum::<u64>();
-    dbg!(p_2);
+    debug!("p_2 = {p_2}");
 
     Ok((p_1, p_2))
 }
@@ -113,8 +113,8 @@ fn numeric_transition(a: IVec2, b: IVec2) -> SmallVec<[SmallVec<[Direction; 5]>;
             // going right and down
             let mut out = smallvec![];
             let mut right_down = smallvec![];
-            right_down.extend(vec![Direction::Right; (a.x - b.x).abs() as usize]);
-            right_down.extend(vec![Direction::Down; (a.y - b.y).abs() as usize]);
+            right_down.extend(vec![Direction::Right; (a.x - b.x).unsigned_abs() as usize]);
+            right_down.extend(vec![Direction::Down; (a.y - b.y).unsigned_abs() as usize]);
             if b.y != 3 || a.x != 0 {
                 let mut down_right = right_down.clone();
                 down_right.reverse();
@@ -128,8 +128,8 @@ fn numeric_transition(a: IVec2, b: IVec2) -> SmallVec<[SmallVec<[Direction; 5]>;
             // going left and up
             let mut out = smallvec![];
             let mut up_left = smallvec![];
-            up_left.extend(vec![Direction::Up; (a.y - b.y).abs() as usize]);
-            up_left.extend(vec![Direction::Left; (a.x - b.x).abs() as usize]);
+            up_left.extend(vec![Direction::Up; (a.y - b.y).unsigned_abs() as usize]);
+            up_left.extend(vec![Direction::Left; (a.x - b.x).unsigned_abs() as usize]);
             if a.y != 3 || b.x != 0 {
                 // can move left first
                 let mut left_up = up_left.clone();
@@ -144,8 +144,8 @@ fn numeric_transition(a: IVec2, b: IVec2) -> SmallVec<[SmallVec<[Direction; 5]>;
             // going left and down
             let mut out = smallvec![];
             let mut left_down = smallvec![];
-            left_down.extend(vec![Direction::Left; (a.x - b.x).abs() as usize]);
-            left_down.extend(vec![Direction::Down; (a.y - b.y).abs() as usize]);
+            left_down.extend(vec![Direction::Left; (a.x - b.x).unsigned_abs() as usize]);
+            left_down.extend(vec![Direction::Down; (a.y - b.y).unsigned_abs() as usize]);
             if b.y != 3 || a.x != 0 {
                 // can move down first
                 let mut down_left = left_down.clone();
@@ -160,8 +160,8 @@ fn numeric_transition(a: IVec2, b: IVec2) -> SmallVec<[SmallVec<[Direction; 5]>;
             // going right and up
             let mut out = smallvec![];
             let mut right_up = smallvec![];
-            right_up.extend(vec![Direction::Up; (a.y - b.y).abs() as usize]);
-            right_up.extend(vec![Direction::Right; (a.x - b.x).abs() as usize]);
+            right_up.extend(vec![Direction::Up; (a.y - b.y).unsigned_abs() as usize]);
+            right_up.extend(vec![Direction::Right; (a.x - b.x).unsigned_abs() as usize]);
             let mut up_right = right_up.clone();
             up_right.reverse();
             out.push(up_right);
@@ -190,7 +190,7 @@ fn solve(seq: &str, depth: usize) -> u64 {
         })
     }));
 
-    println!(
+    trace!(
         "{:?}: \n  {}",
         seq,
         all_transitions
@@ -213,7 +213,7 @@ fn solve(seq: &str, depth: usize) -> u64 {
         .min()
         .unwrap();
 
-    println!("{seq}: {out}");
+    debug!("{seq}: {out}");
 
     out
 }
@@ -311,7 +311,7 @@ fn recursive(a: Direction, b: Direction, depth: usize) -> u64 {
         .min()
         .unwrap();
 
-    println!("{:?} -> {:?} @ {}: {}", a, b, depth, out);
+    trace!("{:?} -> {:?} @ {}: {}", a, b, depth, out);
 
     out
 }
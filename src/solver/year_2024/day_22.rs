@@ -1,5 +1,8 @@
-use hashbrown::{HashMap, HashSet};
+use crate::profile;
+use hashbrown::HashSet;
 use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
 use std::fmt::Display;
 use std::io::BufRead;
 
@@ -19,13 +22,18 @@ pub fn part_1(input: Box<dyn BufRead>) -> anyhow::Result<impl Display> {
 }
 
 pub fn part_2(input: Box<dyn BufRead>) -> anyhow::Result<impl Display> {
+    let span = profile::span("parse");
     let secrets = input
         .lines()
         .map(|s| s.unwrap().parse::<u64>().unwrap())
         .collect_vec();
+    drop(span);
 
-    let costs_differences = secrets.iter().copied().map(|s| gen(s)).collect_vec();
+    let span = profile::span("generate");
+    let costs_differences = secrets.iter().copied().map(gen).collect_vec();
+    drop(span);
 
+    let _span = profile::span("search");
     let mut p_2 = 0;
     let mut cache = HashSet::<[i64; 4]>::new();
     for seq in costs_differences.iter().flat_map(|(_, d)| d.windows(4)) {
@@ -69,7 +77,7 @@ fn gen(mut secret: u64) -> (Vec<u64>, Vec<i64>) {
 
 fn sell(sequence: [i64; 4], differences: &[i64], costs: &[u64]) -> u64 {
     for i in 0..(differences.len() - sequence.len() + 1) {
-        if &differences[i..i + 4] == sequence {
+        if differences[i..i + 4] == sequence {
             return costs[i + 4];
         }
     }
@@ -105,3 +113,10 @@ fn mix(secret: u64, other: u64) -> u64 {
 fn prune(secret: u64) -> u64 {
     secret % 16777216
 }
+
+/// `size` initial secrets, one per line.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{}\n", rng.gen_range(1..16777216u64)))
+        .collect()
+}
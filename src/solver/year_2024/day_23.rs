@@ -1,8 +1,5 @@
-use hashbrown::{HashMap, HashSet};
-use indexmap::{IndexMap, IndexSet};
+use indexmap::IndexSet;
 use itertools::Itertools;
-use petgraph::data::Build;
-use petgraph::prelude::NodeIndex;
 use std::fmt::Display;
 use std::io::BufRead;
 
@@ -30,7 +27,7 @@ pub fn solve(input: Box<dyn BufRead>) -> anyhow::Result<(impl Display, impl Disp
 
     let mut p_1 = 0;
     for (a, b, c) in graph.nodes().tuple_combinations() {
-        if ![a[0], b[0], c[0]].iter().any(|c| *c == 't') {
+        if ![a[0], b[0], c[0]].contains(&'t') {
             continue;
         }
         if !graph.contains_edge(a, b) || !graph.contains_edge(b, c) || !graph.contains_edge(c, a) {
@@ -72,7 +72,7 @@ impl FromStr for Operation {
 
 type Graph = DiGraphMap<NodeId, Operation>;
 
-pub fn parse(input: Box<dyn BufRead>) -> (HashMap<NodeId, bool>, Graph) {
+fn parse(input: Box<dyn BufRead>) -> (HashMap<NodeId, bool>, Graph) {
     let mut lines = input.lines().map(Result::unwrap).peekable();
 
     let inputs = lines
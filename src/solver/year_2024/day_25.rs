@@ -7,11 +7,7 @@ pub fn part_1(input: Box<dyn BufRead>) -> anyhow::Result<impl Display> {
     let mut out = 0;
     for lock in locks {
         for key in keys.clone() {
-            if lock
-                .into_iter()
-                .zip(key.into_iter())
-                .all(|(a, b)| a + b <= 7)
-            {
+            if lock.into_iter().zip(key).all(|(a, b)| a + b <= 7) {
                 out += 1;
             }
         }
@@ -21,7 +17,7 @@ pub fn part_1(input: Box<dyn BufRead>) -> anyhow::Result<impl Display> {
 }
 
 fn parse(input: Box<dyn BufRead>) -> (Vec<[u8; 5]>, Vec<[u8; 5]>) {
-    let mut lines = input.lines().map(|x| x.unwrap());
+    let lines = input.lines().map(|x| x.unwrap());
 
     let mut lines = lines.peekable();
 
@@ -1,3 +1,5 @@
+use rand::rngs::StdRng;
+use rand::Rng;
 use std::io::BufRead;
 
 pub fn part_1(input: Box<dyn BufRead>) -> anyhow::Result<u64> {
@@ -69,6 +71,7 @@ pub fn part_2(input: Box<dyn BufRead>) -> anyhow::Result<u64> {
             right -= 1;
             continue;
         };
+        #[allow(clippy::mut_range_bound)] // breaks right after moving `right`
         for i in 0..right {
             if blocks[i].0.is_none() && blocks[i].1 >= blocks[right].1 {
                 blocks[i].1 -= blocks[right].1;
@@ -106,3 +109,16 @@ pub fn part_2(input: Box<dyn BufRead>) -> anyhow::Result<u64> {
 
     Ok(p_2)
 }
+
+/// A disk map with `size` files, each followed by a gap except the last.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut map = String::with_capacity(size * 2);
+    for i in 0..size.max(1) {
+        if i > 0 {
+            map.push(char::from(b'0' + rng.gen_range(0..=9)));
+        }
+        map.push(char::from(b'0' + rng.gen_range(1..=9)));
+    }
+    map.push('\n');
+    map
+}
@@ -44,7 +44,10 @@ pub fn add_all(solver_database: &mut SolverDatabase) {
         (Day::new(11, 2024), Solver::combined(day_11::part_1)),
         (Day::new(12, 2024), Solver::combined(day_12::solve)),
         (Day::new(13, 2024), Solver::combined(day_13::solve)),
-        (Day::new(14, 2024), Solver::separated((day_14::part_1, ()))),
+        (
+            Day::new(14, 2024),
+            Solver::separated((day_14::part_1, day_14::part_2)),
+        ),
         (Day::new(15, 2024), Solver::combined(day_15::part_1)),
         (Day::new(16, 2024), Solver::combined(day_16::solve)),
         (
@@ -67,4 +70,19 @@ pub fn add_all(solver_database: &mut SolverDatabase) {
     for (day, solver) in solvers {
         solver_database.add_solver(day, solver);
     }
+
+    solver_database.add_variant(
+        Day::new(10, 2024),
+        "search",
+        Solver::combined(day_10::solve_search),
+    );
+    solver_database.add_variant(
+        Day::new(17, 2024),
+        "hope",
+        Solver::separated((day_17::part_1, day_17::part_2_hope)),
+    );
+
+    solver_database.add_generator(Day::new(9, 2024), day_9::generate);
+    solver_database.add_visualizer(Day::new(14, 2024), day_14::visualize);
+    solver_database.add_generator(Day::new(22, 2024), day_22::generate);
 }
@@ -46,7 +46,7 @@ fn is_invalid_id_2(i: i64) -> bool {
     let s = i.to_string();
 
     'outer: for i in 2..=s.len() {
-        if s.len() % i != 0 {
+        if !s.len().is_multiple_of(i) {
             continue;
         }
 
@@ -62,7 +62,7 @@ fn is_invalid_id_2(i: i64) -> bool {
         return true;
     }
 
-    return false;
+    false
 }
 
 #[test]
@@ -7,7 +7,7 @@ use itertools::Itertools;
 pub fn part_1(input: Box<dyn BufRead>) -> anyhow::Result<(usize, usize)> {
     let mut ranges: Vec<Ri> = Vec::new();
     let mut lines = input.lines().map(Result::unwrap);
-    while let Some(line) = lines.next() {
+    for line in lines.by_ref() {
         let line: String = line;
         if line.is_empty() {
             break;
@@ -89,8 +89,8 @@ impl Operation {
 
     fn fold(&self, iter: impl Iterator<Item = u64>) -> u64 {
         match self {
-            Self::Add => iter.fold(0, |acc, e| acc + e),
-            Self::Multiply => iter.fold(1, |acc, e| acc * e),
+            Self::Add => iter.sum(),
+            Self::Multiply => iter.product(),
         }
     }
 }
@@ -0,0 +1,194 @@
+use crate::crypt;
+use crate::day::{Answer, Day, DayInfo, DaysMeta, Example, Solver, SolverDatabase};
+use crate::runner::DaySolver;
+
+/// What exists for a day: its solver and what the meta knows about it.
+pub struct DayStatus {
+    pub day: Day,
+    pub solver: Option<SolverStatus>,
+    pub meta: Option<MetaStatus>,
+}
+
+pub struct SolverStatus {
+    pub kind: SolverKind,
+    /// Whether each part is solved rather than a placeholder
+    pub parts: [bool; 2],
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SolverKind {
+    Combined,
+    Separated,
+    /// A command from the meta
+    External,
+    /// Loaded from a shared library in the plugins directory
+    Plugin,
+}
+
+pub struct MetaStatus {
+    /// Whether every example file exists
+    pub example: bool,
+    pub full: bool,
+    /// Parts of the example with an expected answer
+    pub expected: [bool; 2],
+    /// Parts with an answer accepted on submission
+    pub verified: [bool; 2],
+}
+
+/// Every day with a solver or a meta entry, in order, optionally only for `year`.
+pub fn statuses(meta: &DaysMeta, year: Option<i32>) -> Vec<DayStatus> {
+    let database = SolverDatabase::global();
+    let mut days = database.days();
+    days.extend(meta.0.keys().filter_map(|k| k.parse::<Day>().ok()));
+    days.retain(|day| year.is_none_or(|year| day.year == year));
+    days.sort_unstable();
+    days.dedup();
+
+    days.into_iter()
+        .map(|day| DayStatus {
+            day,
+            solver: DaySolver::find(day, meta.get_day(day)).map(|s| {
+                let plugin = database.get_plugin(&day).is_some();
+                SolverStatus::new(&s, plugin)
+            }),
+            meta: meta.get_day(day).map(MetaStatus::new),
+        })
+        .collect()
+}
+
+impl SolverStatus {
+    fn new(solver: &DaySolver, plugin: bool) -> Self {
+        Self {
+            kind: match solver {
+                DaySolver::Native(_) if plugin => SolverKind::Plugin,
+                DaySolver::Native(Solver::Combined(_)) => SolverKind::Combined,
+                DaySolver::Native(Solver::Separated(_)) => SolverKind::Separated,
+                DaySolver::External(_) => SolverKind::External,
+            },
+            parts: solver.implemented_parts(),
+        }
+    }
+}
+
+impl MetaStatus {
+    fn new(info: &DayInfo) -> Self {
+        let (example, expected) = match &info.example {
+            Example::Single {
+                path,
+                expected_answer,
+            } => (path.exists(), parts(Some(expected_answer))),
+            Example::Multi { path_1, path_2, .. } => {
+                (path_1.exists() && path_2.exists(), [true, true])
+            }
+        };
+
+        Self {
+            example,
+            full: crypt::exists(&info.full),
+            expected,
+            verified: parts(info.verified.as_ref()),
+        }
+    }
+}
+
+/// A year's advent calendar: the stars earned each day, from the verified answers.
+pub struct Calendar {
+    pub year: i32,
+    pub days: Vec<CalendarDay>,
+}
+
+pub struct CalendarDay {
+    pub day: i32,
+    /// Parts with an answer accepted on submission, each worth a star
+    pub stars: [bool; 2],
+    /// Parts with a solver registered for them, whether or not they are verified
+    pub solved: [bool; 2],
+}
+
+impl Calendar {
+    pub fn stars(&self) -> usize {
+        self.days
+            .iter()
+            .flat_map(|d| d.stars)
+            .filter(|&star| star)
+            .count()
+    }
+
+    pub fn max_stars(&self) -> usize {
+        self.days.len() * 2
+    }
+}
+
+/// Number of puzzles in a year, which is 12 since 2025.
+pub fn days_in_year(year: i32) -> i32 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// The calendar of every year with a solver or a meta entry, or only of `year`.
+pub fn calendars(meta: &DaysMeta, year: Option<i32>) -> Vec<Calendar> {
+    let statuses = statuses(meta, year);
+    let mut years = statuses.iter().map(|s| s.day.year).collect::<Vec<_>>();
+    years.extend(year);
+    years.sort_unstable();
+    years.dedup();
+
+    years
+        .into_iter()
+        .map(|year| Calendar {
+            year,
+            days: (1..=days_in_year(year))
+                .map(|day| {
+                    let status = statuses.iter().find(|s| s.day == Day { day, year });
+                    CalendarDay {
+                        day,
+                        stars: status
+                            .and_then(|s| s.meta.as_ref())
+                            .map_or([false, false], |m| m.verified),
+                        solved: status
+                            .and_then(|s| s.solver.as_ref())
+                            .map_or([false, false], |s| s.parts),
+                    }
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+fn parts(answer: Option<&Answer>) -> [bool; 2] {
+    answer.map_or([false, false], |a| {
+        [a.part_1().is_some(), a.part_2().is_some()]
+    })
+}
+
+#[test]
+fn should_count_verified_stars() {
+    // given
+    let mut meta = DaysMeta::default();
+    meta.0.insert(
+        "3-2025".to_string(),
+        DayInfo {
+            full: Default::default(),
+            example: Example::Single {
+                path: Default::default(),
+                expected_answer: Answer::p1("1"),
+            },
+            verified: Some(Answer::both("4", "5")),
+            rejected: vec![],
+            command: None,
+            budget_ms: None,
+        },
+    );
+    // when
+    let calendars = calendars(&meta, Some(2025));
+    // then
+    let [calendar] = &calendars[..] else {
+        panic!("expected only 2025")
+    };
+    assert_eq!(12, calendar.days.len());
+    assert_eq!((2, 24), (calendar.stars(), calendar.max_stars()));
+    assert_eq!([true, true], calendar.days[2].stars);
+}
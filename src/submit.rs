@@ -0,0 +1,181 @@
+use crate::day::Day;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::{env, fs};
+use thiserror::Error;
+
+const DEFAULT_ENDPOINT: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/Niashi24/advent-of-code";
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SubmitOutcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited { wait: Option<String> },
+    AlreadySolved,
+    Unknown,
+}
+
+impl SubmitOutcome {
+    /// Classifies the page returned after posting an answer.
+    pub fn classify(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            SubmitOutcome::Correct
+        } else if body.contains("You gave an answer too recently") {
+            let wait = body
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            SubmitOutcome::RateLimited { wait }
+        } else if body.contains("That's not the right answer") {
+            if body.contains("your answer is too high") {
+                SubmitOutcome::TooHigh
+            } else if body.contains("your answer is too low") {
+                SubmitOutcome::TooLow
+            } else {
+                SubmitOutcome::Wrong
+            }
+        } else if body.contains("You don't seem to be solving the right level") {
+            SubmitOutcome::AlreadySolved
+        } else {
+            SubmitOutcome::Unknown
+        }
+    }
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "correct"),
+            SubmitOutcome::Wrong => write!(f, "wrong"),
+            SubmitOutcome::TooHigh => write!(f, "wrong (too high)"),
+            SubmitOutcome::TooLow => write!(f, "wrong (too low)"),
+            SubmitOutcome::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, wait {wait}")
+            }
+            SubmitOutcome::RateLimited { wait: None } => write!(f, "rate limited"),
+            SubmitOutcome::AlreadySolved => write!(f, "already solved or locked"),
+            SubmitOutcome::Unknown => write!(f, "unrecognized response"),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum SubmitError {
+    #[error("no session token, set AOC_SESSION or session_file in aoc.toml")]
+    NoSession,
+    #[error(transparent)]
+    Http(#[from] Box<ureq::Error>),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+pub struct SubmitClient {
+    endpoint: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl SubmitClient {
+    pub fn new(endpoint: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Reads the session token from `AOC_SESSION`, or else from `session_file`, and the endpoint
+    /// from `AOC_ENDPOINT`, falling back to adventofcode.com.
+    pub fn from_env(session_file: Option<&Path>) -> Result<Self, SubmitError> {
+        let session = match (env::var("AOC_SESSION"), session_file) {
+            (Ok(session), _) => session,
+            (Err(_), Some(path)) => fs::read_to_string(path)?.trim().to_string(),
+            (Err(_), None) => return Err(SubmitError::NoSession),
+        };
+        let endpoint = env::var("AOC_ENDPOINT").unwrap_or_else(|_| DEFAULT_ENDPOINT.to_string());
+        Ok(Self::new(endpoint, session))
+    }
+
+    pub fn submit(&self, day: Day, level: u8, answer: &str) -> Result<SubmitOutcome, SubmitError> {
+        let url = format!("{}/{}/day/{}/answer", self.endpoint, day.year, day.day);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level.to_string()), ("answer", answer)])
+            .map_err(Box::new)?;
+
+        Ok(SubmitOutcome::classify(&response.into_string()?))
+    }
+}
+
+#[test]
+fn should_classify_responses() {
+    let cases = [
+        ("<p>That's the right answer!  You are one gold star closer.</p>", SubmitOutcome::Correct),
+        ("<p>That's not the right answer.  If you're stuck...</p>", SubmitOutcome::Wrong),
+        ("<p>That's not the right answer; your answer is too high.</p>", SubmitOutcome::TooHigh),
+        ("<p>That's not the right answer; your answer is too low.</p>", SubmitOutcome::TooLow),
+        (
+            "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.</p>",
+            SubmitOutcome::RateLimited { wait: Some("37s".to_string()) },
+        ),
+        ("<p>You don't seem to be solving the right level.  Did you already complete it?</p>", SubmitOutcome::AlreadySolved),
+        ("<html></html>", SubmitOutcome::Unknown),
+    ];
+
+    for (body, expected) in cases {
+        assert_eq!(expected, SubmitOutcome::classify(body));
+    }
+}
+
+#[test]
+fn should_post_to_local_server() {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    // given
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut head = Vec::new();
+        let mut length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line == "\r\n" {
+                break;
+            }
+            if let Some(l) = line.to_ascii_lowercase().strip_prefix("content-length: ") {
+                length = l.trim().parse().unwrap();
+            }
+            head.push(line);
+        }
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+
+        let page = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+        write!(
+            reader.get_mut(),
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+            page.len(),
+            page
+        )
+        .unwrap();
+        (head, String::from_utf8(body).unwrap())
+    });
+    let client = SubmitClient::new(endpoint, "abc");
+    // when
+    let outcome = client.submit(Day::new(9, 2024), 1, "1234").unwrap();
+    // then
+    let (head, body) = server.join().unwrap();
+    assert_eq!(SubmitOutcome::TooLow, outcome);
+    assert_eq!("POST /2024/day/9/answer HTTP/1.1\r\n", head[0]);
+    assert!(head.iter().any(|h| h == "Cookie: session=abc\r\n"));
+    assert_eq!("level=1&answer=1234", body);
+}
@@ -0,0 +1,294 @@
+//! Frames a day can produce to show how it gets to its answer, played back in the terminal or
+//! exported as images.
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{cursor, execute, queue};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufWriter, IsTerminal, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use utils::grid::Grid;
+
+/// One step of a visualization.
+pub struct Frame {
+    pub grid: Grid<char>,
+    pub caption: String,
+}
+
+impl Frame {
+    pub fn new(grid: Grid<char>, caption: impl Into<String>) -> Self {
+        Self {
+            grid,
+            caption: caption.into(),
+        }
+    }
+}
+
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+
+/// Turns an input into frames, produced lazily so that long visualizations start right away.
+pub trait Visualize: 'static + Send + Sync {
+    fn frames(&self, input: Box<dyn BufRead>) -> anyhow::Result<Frames>;
+}
+
+impl<I, F> Visualize for F
+where
+    I: Iterator<Item = Frame> + 'static,
+    F: Fn(Box<dyn BufRead>) -> anyhow::Result<I> + 'static + Send + Sync,
+{
+    fn frames(&self, input: Box<dyn BufRead>) -> anyhow::Result<Frames> {
+        Ok(Box::new(self(input)?))
+    }
+}
+
+const CONTROLS: &str = "space pause, ←/→ step, +/- speed, q quit";
+
+/// Plays `frames` at `fps`, with keyboard controls when stdout is a terminal.
+///
+/// Without a terminal every frame is printed after the other instead.
+pub fn play(frames: Frames, fps: u32) -> anyhow::Result<()> {
+    if !io::stdout().is_terminal() {
+        let mut out = io::stdout().lock();
+        for frame in frames {
+            write!(out, "{}\n{}", frame.caption, frame.grid)?;
+        }
+        return Ok(());
+    }
+
+    let _terminal = RawTerminal::enter()?;
+    Player {
+        frames,
+        seen: Vec::new(),
+        index: 0,
+        fps: fps.max(1),
+        paused: false,
+    }
+    .run()
+}
+
+struct Player {
+    frames: Frames,
+    /// Frames produced so far, for stepping back
+    seen: Vec<Frame>,
+    index: usize,
+    fps: u32,
+    paused: bool,
+}
+
+impl Player {
+    fn run(mut self) -> anyhow::Result<()> {
+        if !self.load(0) {
+            return Ok(());
+        }
+
+        let mut next = Instant::now();
+        loop {
+            self.draw()?;
+            next += Duration::from_secs(1) / self.fps;
+
+            // wait for the next frame, handling keys in the meantime
+            loop {
+                let timeout = if self.paused {
+                    Duration::from_secs(3600)
+                } else {
+                    next.saturating_duration_since(Instant::now())
+                };
+                if !event::poll(timeout)? {
+                    break;
+                }
+                let Event::Key(key) = event::read()? else {
+                    continue;
+                };
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match key {
+                    KeyEvent {
+                        code: KeyCode::Char('q') | KeyCode::Esc,
+                        ..
+                    } => return Ok(()),
+                    KeyEvent {
+                        code: KeyCode::Char('c'),
+                        modifiers: KeyModifiers::CONTROL,
+                        ..
+                    } => return Ok(()),
+                    KeyEvent {
+                        code: KeyCode::Char(' '),
+                        ..
+                    } => {
+                        self.paused = !self.paused;
+                        next = Instant::now();
+                    }
+                    KeyEvent {
+                        code: KeyCode::Right | KeyCode::Char('n'),
+                        ..
+                    } => {
+                        self.paused = true;
+                        self.step(1);
+                    }
+                    KeyEvent {
+                        code: KeyCode::Left | KeyCode::Char('p'),
+                        ..
+                    } => {
+                        self.paused = true;
+                        self.step(-1);
+                    }
+                    KeyEvent {
+                        code: KeyCode::Char('+' | '='),
+                        ..
+                    } => self.fps = (self.fps * 2).min(1000),
+                    KeyEvent {
+                        code: KeyCode::Char('-'),
+                        ..
+                    } => self.fps = (self.fps / 2).max(1),
+                    _ => continue,
+                }
+                self.draw()?;
+            }
+
+            if !self.paused && !self.step(1) {
+                // stay on the last frame so that it can be looked at and stepped back from
+                self.paused = true;
+            }
+        }
+    }
+
+    /// Makes sure frame `index` was produced, returning false if there are fewer frames.
+    fn load(&mut self, index: usize) -> bool {
+        while self.seen.len() <= index {
+            match self.frames.next() {
+                Some(frame) => self.seen.push(frame),
+                None => return false,
+            }
+        }
+        true
+    }
+
+    fn step(&mut self, by: isize) -> bool {
+        let Some(index) = self.index.checked_add_signed(by) else {
+            return false;
+        };
+        if !self.load(index) {
+            return false;
+        }
+        self.index = index;
+        true
+    }
+
+    fn draw(&self) -> io::Result<()> {
+        let frame = &self.seen[self.index];
+        let mut out = io::stdout().lock();
+        queue!(out, cursor::MoveTo(0, 0), Clear(ClearType::All))?;
+        for row in &frame.grid.grid {
+            queue!(out, Print(row.iter().collect::<String>()), Print("\r\n"))?;
+        }
+        let state = if self.paused { "paused" } else { "playing" };
+        queue!(
+            out,
+            Print(format!("{}\r\n", frame.caption)),
+            Print(format!(
+                "frame {} · {} fps · {state} · {CONTROLS}",
+                self.index + 1,
+                self.fps
+            ))
+        )?;
+        out.flush()
+    }
+}
+
+/// Raw mode on the alternate screen, restored when dropped.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, cursor::Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Writes every frame to `dir` as `frame-00001.png` and so on, with `scale` pixels per cell,
+/// and the captions to `captions.txt`. Returns the number of frames.
+pub fn export(frames: Frames, dir: &Path, scale: u32) -> anyhow::Result<usize> {
+    fs::create_dir_all(dir)?;
+    let mut captions = BufWriter::new(File::create(dir.join("captions.txt"))?);
+
+    let mut count = 0;
+    for (i, frame) in frames.enumerate() {
+        let name = format!("frame-{:05}.png", i + 1);
+        write_png(&frame.grid, &dir.join(&name), scale)?;
+        writeln!(captions, "{name}\t{}", frame.caption)?;
+        count += 1;
+    }
+    captions.flush()?;
+
+    Ok(count)
+}
+
+fn write_png(grid: &Grid<char>, path: &Path, scale: u32) -> anyhow::Result<()> {
+    let scale = scale.max(1) as usize;
+    let (w, h) = (grid.w * scale, grid.h * scale);
+
+    let mut pixels = Vec::with_capacity(w * h * 3);
+    for row in &grid.grid {
+        let line = row
+            .iter()
+            .flat_map(|&c| std::iter::repeat_n(color(c), scale))
+            .flatten()
+            .collect::<Vec<_>>();
+        for _ in 0..scale {
+            pixels.extend_from_slice(&line);
+        }
+    }
+
+    let mut encoder = png::Encoder::new(BufWriter::new(File::create(path)?), w as u32, h as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&pixels)?;
+    Ok(())
+}
+
+/// Empty cells are dark, walls light and anything else gets a color of its own.
+fn color(c: char) -> [u8; 3] {
+    const PALETTE: [[u8; 3]; 6] = [
+        [230, 80, 70],
+        [90, 200, 90],
+        [80, 140, 240],
+        [240, 200, 60],
+        [200, 100, 220],
+        [70, 210, 210],
+    ];
+    match c {
+        '.' | ' ' => [20, 20, 30],
+        '#' => [235, 235, 235],
+        c => PALETTE[c as usize % PALETTE.len()],
+    }
+}
+
+#[test]
+fn should_export_numbered_frames() {
+    // given
+    let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+    let frames = (1..=3).map(|n| {
+        let grid = Grid::new(vec![vec!['#'; n], vec!['.'; n]]);
+        Frame::new(grid, format!("step {n}"))
+    });
+    // when
+    let count = export(Box::new(frames), &dir, 2).unwrap();
+    let captions = fs::read_to_string(dir.join("captions.txt")).unwrap();
+    let third = dir.join("frame-00003.png").exists();
+    fs::remove_dir_all(&dir).unwrap();
+    // then
+    assert_eq!(3, count);
+    assert!(third);
+    assert_eq!("frame-00001.png\tstep 1", captions.lines().next().unwrap());
+}
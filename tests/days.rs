@@ -0,0 +1,122 @@
+//! One test per day and per example in the meta, checking the solver against the expected answer.
+//!
+//! Checks on the full inputs are ignored by default since the inputs may not be present;
+//! run them with `cargo test --test days -- --ignored full::`.
+
+use advent_of_code::cli::PartArgs;
+use advent_of_code::config::Config;
+use advent_of_code::context::Input;
+use advent_of_code::day::{Answer, Day, DayInfo, DaysMeta, Example, Verdict};
+use advent_of_code::diff::AnswerDiff;
+use advent_of_code::runner::DaySolver;
+use advent_of_code::{crypt, runner};
+use libtest_mimic::{Arguments, Failed, Trial};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+fn main() {
+    colored::control::set_override(false);
+    let args = Arguments::from_args();
+    let config = Config::load().expect("aoc.toml should be valid");
+    crypt::set_key_file(config.key_file());
+    let meta = DaysMeta::load(&config.meta_path()).expect("meta should be readable");
+
+    let mut trials = Vec::new();
+    for (key, info) in meta.0 {
+        let day: Day = key.parse().expect("meta keys should be DAY-YEAR");
+        let name = format!("{}::day_{:02}", day.year, day.day);
+        // examples of parts that are only placeholders are ignored rather than failed
+        let implemented =
+            DaySolver::find(day, Some(&info)).map_or([true, true], |s| s.implemented_parts());
+        let unfinished = |expected: &Answer| {
+            (expected.part_1().is_some() && !implemented[0])
+                || (expected.part_2().is_some() && !implemented[1])
+        };
+
+        match info.example.clone() {
+            Example::Single {
+                path,
+                expected_answer,
+            } => trials.push({
+                let info = info.clone();
+                let ignored = unfinished(&expected_answer);
+                Trial::test(format!("example::{name}"), move || {
+                    check(day, &info, path, expected_answer)
+                })
+                .with_ignored_flag(ignored)
+            }),
+            Example::Multi {
+                path_1,
+                expected_answer_1,
+                path_2,
+                expected_answer_2,
+            } => {
+                let (expected_1, expected_2) =
+                    (Answer::P1(expected_answer_1), Answer::P2(expected_answer_2));
+                let (ignored_1, ignored_2) = (unfinished(&expected_1), unfinished(&expected_2));
+                let info_1 = info.clone();
+                trials.push(
+                    Trial::test(format!("example::{name}::part_1"), move || {
+                        check(day, &info_1, path_1, expected_1)
+                    })
+                    .with_ignored_flag(ignored_1),
+                );
+                let info_2 = info.clone();
+                trials.push(
+                    Trial::test(format!("example::{name}::part_2"), move || {
+                        check(day, &info_2, path_2, expected_2)
+                    })
+                    .with_ignored_flag(ignored_2),
+                );
+            }
+        }
+
+        trials.push(
+            Trial::test(format!("full::{name}"), move || check_full(day, &info))
+                .with_ignored_flag(true),
+        );
+    }
+
+    libtest_mimic::run(&args, trials).exit();
+}
+
+fn check(day: Day, info: &DayInfo, path: PathBuf, expected: Answer) -> Result<(), Failed> {
+    let part = match expected {
+        Answer::P1(_) => PartArgs::P1,
+        Answer::P2(_) => PartArgs::P2,
+        Answer::Both(..) => PartArgs::Both,
+    };
+    let actual = runner::solve(day, info, &part, Input::Example, || {
+        File::open(&path).map(BufReader::new)
+    })?;
+
+    match actual.check(&expected) {
+        Verdict::Passed => Ok(()),
+        Verdict::Unimplemented(level) => Err(format!("part {level} is not implemented").into()),
+        Verdict::Wrong => {
+            Err(format!("wrong answer:\n{}", AnswerDiff::new(&expected, &actual)).into())
+        }
+    }
+}
+
+fn check_full(day: Day, info: &DayInfo) -> Result<(), Failed> {
+    let run = runner::run_full(day, info)?;
+    if let Some(warning) = run.warnings.first() {
+        return Err(warning.to_string().into());
+    }
+
+    let actual = run.result.answer();
+    let Some(verified) = &info.verified else {
+        return Ok(());
+    };
+    match actual.check(verified) {
+        Verdict::Passed => Ok(()),
+        Verdict::Unimplemented(level) => Err(format!("part {level} is not implemented").into()),
+        Verdict::Wrong => Err(format!(
+            "answer differs from the verified one:\n{}",
+            AnswerDiff::new(verified, &actual)
+        )
+        .into()),
+    }
+}
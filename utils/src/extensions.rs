@@ -13,15 +13,16 @@ impl<T> IsNoneOr<T> for Option<T> {
 }
 
 #[test]
+#[allow(clippy::nonminimal_bool)]
 fn is_none_or_works() {
     let x: Option<u32> = Some(2);
-    assert_eq!(x.is_none_or(|x| x > 1), true);
+    assert!(x.is_none_or(|x| x > 1));
 
     let x: Option<u32> = Some(0);
-    assert_eq!(x.is_none_or(|x| x > 1), false);
+    assert!(!x.is_none_or(|x| x > 1));
 
     let x: Option<u32> = None;
-    assert_eq!(x.is_none_or(|x| x > 1), true);
+    assert!(x.is_none_or(|x| x > 1));
 }
 
 pub trait FirstMax<T> {
@@ -33,12 +34,10 @@ impl<T: Ord, It: Iterator<Item = T>> FirstMax<T> for It {
         let mut max = self.next()?;
         let mut max_i = 0;
 
-        let mut i = 1;
-        for item in self {
+        for (i, item) in (1..).zip(self) {
             if item > max {
                 (max, max_i) = (item, i);
             }
-            i += 1;
         }
 
         Some((max_i, max))
@@ -67,7 +67,7 @@ impl<T> Grid<T> {
     }
 
     #[allow(dead_code)]
-    pub fn iter(&self) -> GridIter<T> {
+    pub fn iter(&self) -> GridIter<'_, T> {
         GridIter {
             grid: self,
             x: 0,
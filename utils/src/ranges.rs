@@ -129,12 +129,13 @@ impl<const N: usize> RangeD<N> {
         self.end[i] - self.start[i]
     }
 
-    pub fn iter(&self) -> RangeDIterator<N> {
+    pub fn iter(&self) -> RangeDIterator<'_, N> {
         RangeDIterator::new(self)
     }
 }
 
 #[test]
+#[allow(clippy::single_range_in_vec_init)]
 fn difference_1d() {
     // left side
     let r_1 = RangeD::from_range_1d([0..4]);